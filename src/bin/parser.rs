@@ -959,7 +959,7 @@ fn check<S>(event: &ParsedEventMessage<S>) -> String {
             )
         }
         ParsedEventMessage::WeatherDelivery { delivery: _ } => "".to_string(),
-        ParsedEventMessage::FallingStar { player_name: _ } => "".to_string(),
+        ParsedEventMessage::FallingStar { players: _ } => "".to_string(),
         ParsedEventMessage::FallingStarOutcome {
             deflection,
             player_name: _,
@@ -185,7 +185,7 @@ impl TopBottom {
 }
 
 /// Error for TopBottom's TryFrom<u8> implementation: fails because the given number was not a valid side number.
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Error)]
 pub struct NotASide(pub u8);
 impl Display for NotASide {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -196,6 +196,10 @@ impl Display for NotASide {
         )
     }
 }
+/// Maps an event's `inning_side` to a side: 0 is the top and 1 is the bottom.
+///
+/// mmolb also uses 1 before the game starts (in inning 0) and 2 once it's over, so those
+/// need to be handled before converting. Anything else is a [`NotASide`].
 impl TryFrom<u8> for TopBottom {
     type Error = NotASide;
     fn try_from(value: u8) -> Result<Self, Self::Error> {
@@ -303,6 +307,8 @@ pub enum Inning {
     BeforeGame,
     DuringGame { number: u8, batting_side: TopBottom },
     AfterGame { final_inning_number: u8 },
+    /// The raw values didn't correspond to any known inning state. Kept as-is so they can be written back out.
+    Unknown { number: u8, side: u8 },
 }
 impl Inning {
     /// The next inning. If `continue_if_overtime`, go to extra innings instead of ending the game at the 9th.
@@ -343,7 +349,7 @@ impl Inning {
                     }
                 }
             }
-            Inning::AfterGame { .. } => None,
+            Inning::AfterGame { .. } | Inning::Unknown { .. } => None,
         }
     }
     /// The number of the current inning, during a game.
@@ -1270,6 +1276,8 @@ pub enum SpecialItemType {
     Display,
     PartialEq,
     Eq,
+    PartialOrd,
+    Ord,
     Hash,
     EnumIter,
 )]
@@ -1286,6 +1294,27 @@ pub enum FeedEventType {
     Election,
 }
 
+/// Where a player sits on a roster, as named in promotion and demotion feed events
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    EnumString,
+    IntoStaticStr,
+    Display,
+    PartialEq,
+    Eq,
+    Hash,
+    EnumIter,
+)]
+pub enum RosterSlot {
+    Lineup,
+    Rotation,
+    Bench,
+}
+
 #[derive(
     Debug,
     Clone,
@@ -1435,6 +1464,46 @@ where
         .map_err(|_| D::Error::custom("Expected a number"))
 }
 
+impl FromStr for Day {
+    type Err = &'static str;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Superstar Break" => Ok(Day::SuperstarBreak),
+            "Postseason Preview" => Ok(Day::PostseasonPreview),
+            "Superstar Game" => Ok(Day::SuperstarGame),
+            "Holiday" => Ok(Day::Holiday),
+            "Preseason" => Ok(Day::Preseason),
+            "Election" => Ok(Day::Election),
+            "Event" => Ok(Day::Event),
+            "Special Event" => Ok(Day::SpecialEvent),
+            "Offseason" => Ok(Day::Offseason),
+            s => s
+                .parse()
+                .ok()
+                .map(Day::Day)
+                .or_else(|| {
+                    s.strip_prefix("Superstar Day ")
+                        .and_then(|s| s.parse().ok())
+                        .map(Day::SuperstarDay)
+                })
+                .or_else(|| {
+                    s.strip_prefix("Postseason Round ")
+                        .and_then(|s| s.parse().ok())
+                        .map(Day::PostseasonRound)
+                })
+                .ok_or(()),
+        }
+        .map_err(|_| "Did not match any known Day variants")
+    }
+}
+
+impl TryFrom<&str> for Day {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
 impl Display for Day {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -1785,6 +1854,13 @@ impl TryFrom<Attribute> for AttributeCategory {
     }
 }
 
+impl Attribute {
+    /// None for the few attributes (Priority, Luck) that don't belong to a category
+    pub fn category(self) -> Option<AttributeCategory> {
+        AttributeCategory::try_from(self).ok()
+    }
+}
+
 #[derive(
     EnumString,
     IntoStaticStr,
@@ -2174,6 +2250,8 @@ pub enum EquipmentSlot {
     EnumIter,
     PartialEq,
     Eq,
+    PartialOrd,
+    Ord,
     Hash,
     EnumString,
     IntoStaticStr,
@@ -2345,6 +2423,26 @@ pub enum CelestialEnergyTier {
     FullyCharged,
 }
 
+/// What a payout is paid in. Only tokens so far.
+#[derive(
+    Debug,
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    EnumIter,
+    PartialEq,
+    Eq,
+    Hash,
+    EnumString,
+    IntoStaticStr,
+    Display,
+)]
+pub enum Currency {
+    #[strum(to_string = "🪙")]
+    Tokens,
+}
+
 #[derive(
     Debug,
     Serialize,
@@ -2529,6 +2627,34 @@ mod test {
 
     use super::*;
 
+    #[test]
+    fn attribute_categories() {
+        assert_eq!(Attribute::Contact.category(), Some(AttributeCategory::Batting));
+        assert_eq!(Attribute::Velocity.category(), Some(AttributeCategory::Pitching));
+        assert_eq!(Attribute::Awareness.category(), Some(AttributeCategory::Defense));
+        assert_eq!(Attribute::Speed.category(), Some(AttributeCategory::Baserunning));
+        assert_eq!(Attribute::Luck.category(), None);
+        assert_eq!(Attribute::Priority.category(), None);
+
+        // Every category has at least one attribute in it
+        for category in AttributeCategory::iter() {
+            assert!(Attribute::iter().any(|attribute| attribute.category() == Some(category)));
+        }
+    }
+
+    #[test]
+    fn top_bottom_from_side() {
+        assert_eq!(TopBottom::try_from(0), Ok(TopBottom::Top));
+        assert_eq!(TopBottom::try_from(1), Ok(TopBottom::Bottom));
+        for side in [2, 3, u8::MAX] {
+            assert_eq!(TopBottom::try_from(side), Err(NotASide(side)));
+        }
+
+        for side in TopBottom::iter() {
+            assert_eq!(TopBottom::try_from(u8::from(side)), Ok(side));
+        }
+    }
+
     fn serde_round_trip_inner<
         T: IntoEnumIterator + PartialEq + Debug + Serialize + for<'de> Deserialize<'de>,
     >() {
@@ -2540,6 +2666,24 @@ mod test {
         }
     }
 
+    #[test]
+    fn day_from_str() {
+        assert_eq!(Day::try_from("12"), Ok(Day::Day(12)));
+        assert_eq!(Day::try_from("Superstar Break"), Ok(Day::SuperstarBreak));
+        assert_eq!(Day::try_from("Superstar Day 2"), Ok(Day::SuperstarDay(2)));
+        assert_eq!(Day::try_from("Postseason Round 3"), Ok(Day::PostseasonRound(3)));
+        assert!(Day::try_from("Some New Day").is_err());
+
+        for day in Day::iter().chain([Day::SuperstarDay(1), Day::PostseasonRound(2)]) {
+            assert_eq!(Day::try_from(day.to_string().as_str()), Ok(day));
+        }
+
+        assert!(matches!(
+            crate::utils::maybe_recognized_from_str::<Day>("Some New Day"),
+            Err(crate::utils::NotRecognized(serde_json::Value::String(s))) if s == "Some New Day"
+        ));
+    }
+
     #[test]
     fn serde_round_trips() {
         serde_round_trip_inner::<EventType>();
@@ -2577,4 +2721,29 @@ mod test {
         serde_round_trip_inner::<ModificationType>();
         serde_round_trip_inner::<BallparkSuffix>();
     }
+
+    #[test]
+    fn feed_event_type_ordering() {
+        let mut groups = std::collections::BTreeMap::new();
+        for event_type in [
+            FeedEventType::Election,
+            FeedEventType::Game,
+            FeedEventType::Augment,
+            FeedEventType::Game,
+        ] {
+            *groups.entry(event_type).or_insert(0) += 1;
+        }
+
+        assert_eq!(
+            groups.into_iter().collect::<Vec<_>>(),
+            vec![
+                (FeedEventType::Game, 2),
+                (FeedEventType::Augment, 1),
+                (FeedEventType::Election, 1)
+            ]
+        );
+        assert_eq!(FeedEventType::Game.to_string(), "game");
+        assert!(FeedEventSource::Player < FeedEventSource::Team);
+        assert_eq!(FeedEventSource::Team.to_string(), "Team");
+    }
 }
@@ -1,6 +1,6 @@
 use crate::time::Breakpoints;
 use crate::{
-    enums::{CelestialEnergyTier, Day, FeedEventType, LinkType, SeasonStatus},
+    enums::{CelestialEnergyTier, Day, FeedEventSource, FeedEventType, LinkType, SeasonStatus},
     utils::{
         extra_fields_deserialize, MaybeRecognizedHelper, MaybeRecognizedResult, TimestampHelper,
     },
@@ -34,6 +34,55 @@ pub struct FeedEvent {
     pub extra_fields: serde_json::Map<String, serde_json::Value>,
 }
 
+impl FeedEvent {
+    /// Which feed this event came from, if it says. Events re-served from the
+    /// legacy collections carry a `legacy_source` that records this.
+    pub fn source(&self) -> Option<FeedEventSource> {
+        let collection = self
+            .extra_fields
+            .get("legacy_source")?
+            .get("collection")?
+            .as_str()?;
+
+        match collection {
+            "player" => Some(FeedEventSource::Player),
+            "team" => Some(FeedEventSource::Team),
+            _ => None,
+        }
+    }
+
+    /// The season and day this event happened on. None if the day wasn't recognized.
+    pub fn time(&self) -> Option<(u8, Day)> {
+        self.day.as_ref().ok().map(|day| (self.season, *day))
+    }
+
+    /// The sentences of the text, without their "." or "!" and the space between them. A
+    /// sentence ends at a "." or "!" that's followed by a space or the end of the text, which is
+    /// how the sentence-by-sentence parsers split it too. A name with ". " in it will be split in
+    /// two. Useful for picking apart events this crate doesn't parse yet.
+    pub fn sentences(&self) -> impl Iterator<Item = &str> {
+        let mut rest = self.text.as_str();
+        std::iter::from_fn(move || {
+            rest = rest.trim_start_matches(' ');
+            if rest.is_empty() {
+                return None;
+            }
+
+            let end = rest
+                .match_indices(['.', '!'])
+                .map(|(i, _)| i)
+                .find(|&i| matches!(rest[i + 1..].chars().next(), None | Some(' ')));
+            let (sentence, remaining) = match end {
+                Some(i) => (&rest[..i], &rest[i + 1..]),
+                // No terminator on the last sentence
+                None => (rest, ""),
+            };
+            rest = remaining;
+            Some(sentence)
+        })
+    }
+}
+
 #[serde_as]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct Link {
@@ -103,6 +152,7 @@ mod test {
     use std::path::Path;
 
     use crate::{
+        enums::{Day, FeedEventSource},
         feed_event::FeedEvent,
         utils::{assert_round_trip, no_tracing_errs},
     };
@@ -116,4 +166,72 @@ mod test {
         drop(no_tracing_errs);
         Ok(())
     }
+
+    #[test]
+    fn feed_event_source() -> Result<(), Box<dyn std::error::Error>> {
+        let event: FeedEvent =
+            serde_json::from_str(&std::fs::read_to_string("test_data/s2_feed_event.json")?)?;
+        assert_eq!(event.source(), None);
+
+        let mut event = event;
+        event.extra_fields.insert(
+            "legacy_source".to_string(),
+            serde_json::json!({ "source_id": "6846d5635fb8c747ea2ed48c", "collection": "player" }),
+        );
+        assert_eq!(event.source(), Some(FeedEventSource::Player));
+
+        event.extra_fields["legacy_source"]["collection"] = "team".into();
+        assert_eq!(event.source(), Some(FeedEventSource::Team));
+
+        Ok(())
+    }
+
+    #[test]
+    fn feed_event_sentences() -> Result<(), Box<dyn std::error::Error>> {
+        let mut event: FeedEvent =
+            serde_json::from_str(&std::fs::read_to_string("test_data/s2_feed_event.json")?)?;
+
+        event.text = "Yumi Pratt gained +5 Contact x2. Kelly Vieira lost 0.75 from Aiming. \
+            Nancy Bright's Cunning became equal to their base Intimidation."
+            .to_string();
+        assert_eq!(
+            event.sentences().collect::<Vec<_>>(),
+            vec![
+                "Yumi Pratt gained +5 Contact x2",
+                "Kelly Vieira lost 0.75 from Aiming",
+                "Nancy Bright's Cunning became equal to their base Intimidation",
+            ]
+        );
+
+        event.text = "Yumi Pratt was hit by a Falling Star! Yumi Pratt gained +5 Luck".to_string();
+        assert_eq!(
+            event.sentences().collect::<Vec<_>>(),
+            vec![
+                "Yumi Pratt was hit by a Falling Star",
+                "Yumi Pratt gained +5 Luck"
+            ]
+        );
+
+        event.text = String::new();
+        assert_eq!(event.sentences().count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn feed_event_time() -> Result<(), Box<dyn std::error::Error>> {
+        let mut value: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string("test_data/s2_feed_event.json")?)?;
+        value["season"] = 4.into();
+        value["day"] = 12.into();
+        let event: FeedEvent = serde_json::from_value(value.clone())?;
+        assert_eq!(event.time(), Some((4, Day::Day(12))));
+
+        value["day"] = "Some New Day".into();
+        let event: FeedEvent = serde_json::from_value(value)?;
+        assert!(event.day.is_err());
+        assert_eq!(event.time(), None);
+
+        Ok(())
+    }
 }
@@ -1,15 +1,18 @@
-use std::fmt::Display;
+use std::{borrow::Cow, collections::HashMap, fmt::Display, hash::Hash};
 
-use serde::{Deserialize, Serialize};
+use itertools::Itertools;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use strum::{Display, EnumIter};
 use thiserror::Error;
 
 use crate::{
     enums::{
-        Attribute, CelestialEnergyTier, FeedEventSource, FeedEventType, ItemName, ItemPrefix,
-        ItemSuffix, ModificationType,
+        Attribute, CelestialEnergyTier, Currency, FeedEventSource, FeedEventType, ItemName,
+        ItemPrefix, ItemSuffix, ModificationType,
     },
     feed_event::FeedEvent,
-    parsed_event::{EmojiTeam, Item},
+    nom_parsing::shared::unparse_thousands_separated,
+    parsed_event::{EmojiTeam, Item, ItemAffixes},
     time::{Breakpoints, Timestamp},
     NotRecognized,
 };
@@ -22,7 +25,104 @@ pub enum FeedEventParseError {
     FailedParsingText {
         event_type: FeedEventType,
         text: String,
+        /// Byte offset into `text` where the parser gave up
+        #[serde(default)]
+        consumed: usize,
     },
+    /// The text was empty or only whitespace, so there was nothing to parse
+    #[error("feed event text is empty")]
+    EmptyText,
+    /// The text was longer than [`ParseOptions::max_text_len`], so it wasn't parsed
+    #[error("feed event text is too long ({len} bytes)")]
+    TextTooLong { len: usize },
+}
+
+/// Settings for the `parse_*_feed_event_with` functions. The functions without `_with` use the
+/// defaults.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Texts longer than this many bytes come back as [`FeedEventParseError::TextTooLong`]
+    /// without being parsed. Real events are well under a kilobyte, so the default leaves lots
+    /// of room.
+    pub max_text_len: usize,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            max_text_len: 64 * 1024,
+        }
+    }
+}
+
+/// Which kind of [`FeedEventParseError`] this is, for counting errors without matching on text.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Display,
+    EnumIter,
+)]
+pub enum FeedErrorCode {
+    UnrecognizedEventType,
+    FailedText,
+    EmptyText,
+    TextTooLong,
+}
+
+impl FeedEventParseError {
+    pub fn code(&self) -> FeedErrorCode {
+        match self {
+            FeedEventParseError::EventTypeNotRecognized(_) => FeedErrorCode::UnrecognizedEventType,
+            FeedEventParseError::FailedParsingText { .. } => FeedErrorCode::FailedText,
+            FeedEventParseError::EmptyText => FeedErrorCode::EmptyText,
+            FeedEventParseError::TextTooLong { .. } => FeedErrorCode::TextTooLong,
+        }
+    }
+}
+
+#[cfg(feature = "miette")]
+impl miette::Diagnostic for FeedEventParseError {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        let code = match FeedEventParseError::code(self) {
+            FeedErrorCode::UnrecognizedEventType => {
+                "mmolb_parsing::feed_event::event_type_not_recognized"
+            }
+            FeedErrorCode::FailedText => "mmolb_parsing::feed_event::failed_parsing_text",
+            FeedErrorCode::EmptyText => "mmolb_parsing::feed_event::empty_text",
+            FeedErrorCode::TextTooLong => "mmolb_parsing::feed_event::text_too_long",
+        };
+        Some(Box::new(code))
+    }
+
+    fn source_code(&self) -> Option<&dyn miette::SourceCode> {
+        match self {
+            FeedEventParseError::EventTypeNotRecognized(_)
+            | FeedEventParseError::EmptyText
+            | FeedEventParseError::TextTooLong { .. } => None,
+            FeedEventParseError::FailedParsingText { text, .. } => Some(text),
+        }
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+        match self {
+            FeedEventParseError::EventTypeNotRecognized(_)
+            | FeedEventParseError::EmptyText
+            | FeedEventParseError::TextTooLong { .. } => None,
+            FeedEventParseError::FailedParsingText { text, consumed, .. } => {
+                let consumed = (*consumed).min(text.len());
+                let span = miette::LabeledSpan::at(consumed..text.len(), "couldn't parse this");
+                Some(Box::new(std::iter::once(span)))
+            }
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
@@ -37,8 +137,8 @@ pub enum ParsedFeedEventText<S> {
         /// Sometimes this name is wrong: early season 1 bug where the events didn't have spaces between words.
         away_team: EmojiTeam<S>,
 
-        home_score: u8,
-        away_score: u8,
+        home_score: u16,
+        away_score: u16,
     },
     Delivery {
         delivery: FeedDelivery<S>,
@@ -87,6 +187,15 @@ pub enum ParsedFeedEventText<S> {
         team: EmojiTeam<S>,
         income: u8,
     },
+    /// Speculative, see [`crate::team_feed::ParsedTeamFeedEventText::Payout`]
+    Payout {
+        recipient: S,
+        amount: u32,
+        /// Whether the amount was written with commas, e.g. "1,250"
+        #[serde(default)]
+        thousands_separators: bool,
+        currency: Currency,
+    },
     Recomposed {
         previous: S,
         new: S,
@@ -112,6 +221,12 @@ pub enum ParsedFeedEventText<S> {
 }
 
 impl<S: Display> ParsedFeedEventText<S> {
+    /// Only `SingleAttributeEquals` (in early season 1) depends on the source, so
+    /// `fallback` is only consulted for those when the event doesn't record its source.
+    pub fn unparse_inferred(&self, event: &FeedEvent, fallback: FeedEventSource) -> String {
+        self.unparse(event, event.source().unwrap_or(fallback))
+    }
+
     pub fn unparse(&self, event: &FeedEvent, source: FeedEventSource) -> String {
         match self {
             ParsedFeedEventText::ParseError { text, .. } => text.to_string(),
@@ -133,12 +248,7 @@ impl<S: Display> ParsedFeedEventText<S> {
             ParsedFeedEventText::Shipment { delivery } => delivery.unparse(event, "Shipment"),
             ParsedFeedEventText::AttributeChanges { changes } => changes
                 .iter()
-                .map(|change| {
-                    format!(
-                        "{} gained +{} {}.",
-                        change.player_name, change.amount, change.attribute
-                    )
-                })
+                .map(AttributeChange::unparse_gain)
                 .collect::<Vec<_>>()
                 .join(" "),
             ParsedFeedEventText::SingleAttributeEquals {
@@ -146,35 +256,12 @@ impl<S: Display> ParsedFeedEventText<S> {
                 changing_attribute,
                 value_attribute,
             } => {
-                if Breakpoints::Season3.after(
-                    event.season as u32,
-                    event.day.as_ref().copied().ok(),
-                    None,
-                ) {
-                    format!(
-                        "{}'s {} was set to their {}.",
-                        player_name, changing_attribute, value_attribute
-                    )
-                } else if Breakpoints::S1AttributeEqualChange.after(
-                    event.season as u32,
-                    event.day.as_ref().copied().ok(),
-                    None,
-                ) {
-                    format!(
-                        "{}'s {} became equal to their current base {}.",
-                        player_name, changing_attribute, value_attribute
-                    )
-                } else if FeedEventSource::Player == source {
-                    format!(
-                        "{}'s {} was set to their {}.",
-                        player_name, changing_attribute, value_attribute
-                    )
-                } else {
-                    format!(
-                        "{}'s {} became equal to their base {}.",
-                        player_name, changing_attribute, value_attribute
-                    )
-                }
+                let phrasing = AttributeEqualPhrasing::for_source(event, source);
+                format!(
+                    "{player_name}'s {changing_attribute} {} their {}{value_attribute}.",
+                    phrasing.verb(),
+                    phrasing.base()
+                )
             }
             ParsedFeedEventText::S1Enchantment {
                 player_name,
@@ -233,6 +320,15 @@ impl<S: Display> ParsedFeedEventText<S> {
             ParsedFeedEventText::Prosperous { team, income } => {
                 format!("{team} are Prosperous! They earned {income} 🪙.")
             }
+            ParsedFeedEventText::Payout {
+                recipient,
+                amount,
+                thousands_separators,
+                currency,
+            } => format!(
+                "{recipient} received a payout of {} {currency}.",
+                unparse_thousands_separated(*amount, *thousands_separators)
+            ),
             ParsedFeedEventText::Recomposed { previous, new } => {
                 if event.timestamp > Timestamp::Season3RecomposeChange.timestamp() {
                     format!("{previous} was Recomposed into {new}.")
@@ -267,11 +363,768 @@ impl<S: Display> ParsedFeedEventText<S> {
     }
 }
 
+impl<S: Display + AsRef<str>> ParsedFeedEventText<S> {
+    /// Like `unparse`, but borrows when the text is just echoed back
+    pub fn unparse_cow(&self, event: &FeedEvent, source: FeedEventSource) -> Cow<'_, str> {
+        match self {
+            ParsedFeedEventText::ParseError { text, .. } => Cow::Borrowed(text.as_ref()),
+            _ => Cow::Owned(self.unparse(event, source)),
+        }
+    }
+}
+
+impl<S> ParsedFeedEventText<S> {
+    /// Borrow this event as a [`FeedEventView`]
+    pub fn view(&self) -> FeedEventView<'_, S> {
+        match self {
+            ParsedFeedEventText::ParseError { error, text } => {
+                FeedEventView::ParseError { error, text }
+            }
+            ParsedFeedEventText::GameResult {
+                home_team,
+                away_team,
+                home_score,
+                away_score,
+            } => FeedEventView::GameResult {
+                home_team,
+                away_team,
+                home_score: *home_score,
+                away_score: *away_score,
+            },
+            ParsedFeedEventText::Delivery { delivery } => FeedEventView::Delivery { delivery },
+            ParsedFeedEventText::Shipment { delivery } => FeedEventView::Shipment { delivery },
+            ParsedFeedEventText::SpecialDelivery { delivery } => {
+                FeedEventView::SpecialDelivery { delivery }
+            }
+            ParsedFeedEventText::AttributeChanges { changes } => {
+                FeedEventView::AttributeChanges { changes }
+            }
+            ParsedFeedEventText::SingleAttributeEquals {
+                player_name,
+                changing_attribute,
+                value_attribute,
+            } => FeedEventView::SingleAttributeEquals {
+                player_name,
+                changing_attribute: *changing_attribute,
+                value_attribute: *value_attribute,
+            },
+            ParsedFeedEventText::S1Enchantment {
+                player_name,
+                item,
+                amount,
+                attribute,
+            } => FeedEventView::S1Enchantment {
+                player_name,
+                item: *item,
+                amount: *amount,
+                attribute: *attribute,
+            },
+            ParsedFeedEventText::S2Enchantment {
+                player_name,
+                item,
+                amount,
+                attribute,
+                enchant_two,
+                compensatory,
+            } => FeedEventView::S2Enchantment {
+                player_name,
+                item: *item,
+                amount: *amount,
+                attribute: *attribute,
+                enchant_two: *enchant_two,
+                compensatory: *compensatory,
+            },
+            ParsedFeedEventText::TakeTheMound {
+                to_mound_player,
+                to_lineup_player,
+            } => FeedEventView::TakeTheMound {
+                to_mound_player,
+                to_lineup_player,
+            },
+            ParsedFeedEventText::TakeThePlate {
+                to_plate_player,
+                from_lineup_player,
+            } => FeedEventView::TakeThePlate {
+                to_plate_player,
+                from_lineup_player,
+            },
+            ParsedFeedEventText::SwapPlaces {
+                player_one,
+                player_two,
+            } => FeedEventView::SwapPlaces {
+                player_one,
+                player_two,
+            },
+            ParsedFeedEventText::Prosperous { team, income } => FeedEventView::Prosperous {
+                team,
+                income: *income,
+            },
+            ParsedFeedEventText::Payout {
+                recipient,
+                amount,
+                thousands_separators: _,
+                currency,
+            } => FeedEventView::Payout {
+                recipient,
+                amount: *amount,
+                currency: *currency,
+            },
+            ParsedFeedEventText::Recomposed { previous, new } => {
+                FeedEventView::Recomposed { previous, new }
+            }
+            ParsedFeedEventText::Modification {
+                player_name,
+                modification,
+            } => FeedEventView::Modification {
+                player_name,
+                modification,
+            },
+            ParsedFeedEventText::Retirement { previous, new } => FeedEventView::Retirement {
+                previous,
+                new: new.as_ref(),
+            },
+            ParsedFeedEventText::InjuredByFallingStar { player } => {
+                FeedEventView::InjuredByFallingStar { player }
+            }
+            ParsedFeedEventText::InfusedByFallingStar {
+                player,
+                infusion_tier,
+            } => FeedEventView::InfusedByFallingStar {
+                player,
+                infusion_tier: *infusion_tier,
+            },
+            ParsedFeedEventText::Released { team } => FeedEventView::Released { team },
+        }
+    }
+
+    pub fn display<'a>(
+        &'a self,
+        event: &'a FeedEvent,
+        source: FeedEventSource,
+    ) -> FeedEventDisplay<'a, S> {
+        FeedEventDisplay {
+            parsed: self,
+            event,
+            source,
+        }
+    }
+
+    /// The amount of an `S2Enchantment`'s second bonus. None for other variants and for
+    /// enchantments with only one bonus.
+    pub fn second_amount(&self) -> Option<u8> {
+        self.second_bonus().map(|(amount, _)| amount)
+    }
+
+    /// The attribute of an `S2Enchantment`'s second bonus. None for other variants and for
+    /// enchantments with only one bonus.
+    pub fn second_attribute(&self) -> Option<Attribute> {
+        self.second_bonus().map(|(_, attribute)| attribute)
+    }
+
+    fn second_bonus(&self) -> Option<(u8, Attribute)> {
+        match self {
+            ParsedFeedEventText::S2Enchantment { enchant_two, .. } => *enchant_two,
+            _ => None,
+        }
+    }
+
+    /// Every (amount, attribute) bonus an enchantment gave, primary first. Empty for anything
+    /// that isn't an enchantment.
+    pub fn all_bonuses(&self) -> Vec<(u8, Attribute)> {
+        match self {
+            ParsedFeedEventText::S1Enchantment {
+                amount, attribute, ..
+            } => vec![(*amount, *attribute)],
+            ParsedFeedEventText::S2Enchantment {
+                amount,
+                attribute,
+                enchant_two,
+                ..
+            } => std::iter::once((*amount, *attribute))
+                .chain(*enchant_two)
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// A borrowed view of a [`ParsedFeedEventText`], for matching on it without cloning its
+/// `Vec`s and strings. Get one from [`ParsedFeedEventText::view`].
+#[derive(Debug, PartialEq)]
+pub enum FeedEventView<'a, S> {
+    ParseError {
+        error: &'a FeedEventParseError,
+        text: &'a S,
+    },
+    GameResult {
+        home_team: &'a EmojiTeam<S>,
+        away_team: &'a EmojiTeam<S>,
+        home_score: u16,
+        away_score: u16,
+    },
+    Delivery {
+        delivery: &'a FeedDelivery<S>,
+    },
+    Shipment {
+        delivery: &'a FeedDelivery<S>,
+    },
+    SpecialDelivery {
+        delivery: &'a FeedDelivery<S>,
+    },
+    AttributeChanges {
+        changes: &'a [AttributeChange<S>],
+    },
+    SingleAttributeEquals {
+        player_name: &'a S,
+        changing_attribute: Attribute,
+        value_attribute: Attribute,
+    },
+    S1Enchantment {
+        player_name: &'a S,
+        item: EmojilessItem,
+        amount: u8,
+        attribute: Attribute,
+    },
+    S2Enchantment {
+        player_name: &'a S,
+        item: EmojilessItem,
+        amount: u8,
+        attribute: Attribute,
+        enchant_two: Option<(u8, Attribute)>,
+        compensatory: bool,
+    },
+    TakeTheMound {
+        to_mound_player: &'a S,
+        to_lineup_player: &'a S,
+    },
+    TakeThePlate {
+        to_plate_player: &'a S,
+        from_lineup_player: &'a S,
+    },
+    SwapPlaces {
+        player_one: &'a S,
+        player_two: &'a S,
+    },
+    Prosperous {
+        team: &'a EmojiTeam<S>,
+        income: u8,
+    },
+    Payout {
+        recipient: &'a S,
+        amount: u32,
+        currency: Currency,
+    },
+    Recomposed {
+        previous: &'a S,
+        new: &'a S,
+    },
+    Modification {
+        player_name: &'a S,
+        modification: &'a ModificationType,
+    },
+    Retirement {
+        previous: &'a S,
+        new: Option<&'a S>,
+    },
+    InjuredByFallingStar {
+        player: &'a S,
+    },
+    InfusedByFallingStar {
+        player: &'a S,
+        infusion_tier: CelestialEnergyTier,
+    },
+    Released {
+        team: &'a S,
+    },
+}
+
+// Derived Clone/Copy would require S: Clone, but the view only holds references
+impl<S> Clone for FeedEventView<'_, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S> Copy for FeedEventView<'_, S> {}
+
+/// Unparsing needs the event and its source, so this carries them along for `Display`
+#[derive(Clone, Copy, Debug)]
+pub struct FeedEventDisplay<'a, S> {
+    pub parsed: &'a ParsedFeedEventText<S>,
+    pub event: &'a FeedEvent,
+    pub source: FeedEventSource,
+}
+
+impl<S: Display> Display for FeedEventDisplay<'_, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.parsed.unparse(self.event, self.source))
+    }
+}
+
+/// Serializes a parsed feed event as `{ "type": "Variant", ...fields }` instead of serde's
+/// default `{ "Variant": { ...fields } }`, which suits document stores better.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InternallyTagged<T>(pub T);
+
+impl<T: Serialize> Serialize for InternallyTagged<T> {
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        use serde::ser::Error;
+
+        let tagged = match serde_json::to_value(&self.0).map_err(Ser::Error::custom)? {
+            serde_json::Value::String(variant) => {
+                serde_json::Map::from_iter([("type".to_string(), variant.into())])
+            }
+            serde_json::Value::Object(outer) if outer.len() == 1 => {
+                let (variant, fields) = outer.into_iter().next().unwrap();
+                let serde_json::Value::Object(mut fields) = fields else {
+                    return Err(Ser::Error::custom(format!(
+                        "variant {variant} doesn't have named fields"
+                    )));
+                };
+                if fields.contains_key("type") {
+                    return Err(Ser::Error::custom(format!(
+                        "variant {variant} has a field named \"type\""
+                    )));
+                }
+                fields.insert("type".to_string(), variant.into());
+                fields
+            }
+            other => {
+                return Err(Ser::Error::custom(format!(
+                    "expected an externally tagged enum, got {other}"
+                )))
+            }
+        };
+
+        tagged.serialize(serializer)
+    }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for InternallyTagged<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let mut fields = serde_json::Map::deserialize(deserializer)?;
+        let variant = match fields.remove("type") {
+            Some(serde_json::Value::String(variant)) => variant,
+            _ => return Err(D::Error::missing_field("type")),
+        };
+
+        let external = serde_json::Value::Object(serde_json::Map::from_iter([(
+            variant,
+            serde_json::Value::Object(fields),
+        )]));
+        serde_json::from_value(external)
+            .map(InternallyTagged)
+            .map_err(D::Error::custom)
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct AttributeChange<S> {
     pub player_name: S,
     pub amount: i16,
     pub attribute: Attribute,
+    /// The "x2" some bonuses have after them. None when there isn't one.
+    ///
+    /// Speculative: no multiplied bonus has been captured, so the wording this is parsed from is
+    /// a guess and may never match anything.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub multiplier: Option<u8>,
+}
+
+impl<S> AttributeChange<S> {
+    /// `amount` times the multiplier, if there is one
+    pub fn total(&self) -> i32 {
+        i32::from(self.amount) * i32::from(self.multiplier.unwrap_or(1))
+    }
+}
+
+impl<S> AttributeChange<S> {
+    pub fn map_text<T>(&self, f: &mut impl FnMut(&S) -> T) -> AttributeChange<T> {
+        AttributeChange {
+            player_name: f(&self.player_name),
+            amount: self.amount,
+            attribute: self.attribute,
+            multiplier: self.multiplier,
+        }
+    }
+}
+
+impl<S: Display> AttributeChange<S> {
+    pub(crate) fn unparse_gain(&self) -> String {
+        let multiplier = self
+            .multiplier
+            .map(|multiplier| format!(" x{multiplier}"))
+            .unwrap_or_default();
+        format!(
+            "{} gained +{} {}{multiplier}.",
+            self.player_name, self.amount, self.attribute
+        )
+    }
+}
+
+/// An attribute set to a number rather than to another attribute, like
+/// "Nancy Bright's Awareness dropped to 40."
+///
+/// Speculative: no such line has been captured, so the "dropped to" wording is a guess and may
+/// never match anything.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct AttributeSet<S> {
+    pub player_name: S,
+    pub attribute: Attribute,
+    pub value: i16,
+}
+
+impl<S> AttributeSet<S> {
+    pub fn map_text<T>(&self, f: &mut impl FnMut(&S) -> T) -> AttributeSet<T> {
+        AttributeSet {
+            player_name: f(&self.player_name),
+            attribute: self.attribute,
+            value: self.value,
+        }
+    }
+}
+
+impl<S: Display> Display for AttributeSet<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}'s {} dropped to {}.",
+            self.player_name, self.attribute, self.value
+        )
+    }
+}
+
+/// How an item's prefix or suffix changed in an [`ItemModification`]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ItemModChange {
+    PrefixAdded(ItemPrefix),
+    PrefixRemoved(ItemPrefix),
+    SuffixAdded(ItemSuffix),
+    SuffixRemoved(ItemSuffix),
+}
+
+impl Display for ItemModChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ItemModChange::PrefixAdded(prefix) => write!(f, "became {prefix}"),
+            ItemModChange::PrefixRemoved(prefix) => write!(f, "is no longer {prefix}"),
+            ItemModChange::SuffixAdded(suffix) => write!(f, "became {suffix}"),
+            ItemModChange::SuffixRemoved(suffix) => write!(f, "is no longer {suffix}"),
+        }
+    }
+}
+
+/// An item gaining or losing a prefix or suffix, like "Nancy Bright's Cap became Sharp."
+/// `item` is the item as it was named before the change.
+///
+/// Speculative: no such line has been captured, so the "became"/"is no longer" wording is a guess
+/// and may never match anything.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ItemModification<S> {
+    pub player_name: S,
+    pub item: EmojilessItem,
+    pub change: ItemModChange,
+}
+
+impl<S> ItemModification<S> {
+    pub fn map_text<T>(&self, f: &mut impl FnMut(&S) -> T) -> ItemModification<T> {
+        ItemModification {
+            player_name: f(&self.player_name),
+            item: self.item,
+            change: self.change,
+        }
+    }
+}
+
+impl<S: Display> Display for ItemModification<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}'s {} {}.", self.player_name, self.item, self.change)
+    }
+}
+
+/// The three ways "X's attribute is now Y's attribute" has been worded. Which one is in use has
+/// changed over time, and the breakpoints don't line up exactly with the data, so the parsed
+/// wording is kept to unparse with.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum AttributeEqualPhrasing {
+    /// "X's Contact was set to their Aiming."
+    SetTo,
+    /// "X's Contact became equal to their base Aiming."
+    BecameEqualToBase,
+    /// "X's Contact became equal to their current base Aiming."
+    BecameEqualToCurrentBase,
+}
+
+impl AttributeEqualPhrasing {
+    /// What a player feed would have used at the time of `event`
+    pub fn for_event(event: &FeedEvent) -> Self {
+        if event.after(Breakpoints::S1AttributeEqualChange) && !event.after(Breakpoints::Season3) {
+            AttributeEqualPhrasing::BecameEqualToCurrentBase
+        } else {
+            AttributeEqualPhrasing::SetTo
+        }
+    }
+
+    /// Like [`Self::for_event`], but for either kind of feed. They only differ before the season 1
+    /// attribute-equal wording change, when team feeds said "became equal to their base"
+    /// and player feeds said "was set to".
+    pub fn for_source(event: &FeedEvent, source: FeedEventSource) -> Self {
+        match source {
+            FeedEventSource::Team if !event.after(Breakpoints::S1AttributeEqualChange) => {
+                AttributeEqualPhrasing::BecameEqualToBase
+            }
+            FeedEventSource::Team | FeedEventSource::Player => Self::for_event(event),
+        }
+    }
+
+    pub fn verb(self) -> &'static str {
+        match self {
+            AttributeEqualPhrasing::SetTo => "was set to",
+            AttributeEqualPhrasing::BecameEqualToBase
+            | AttributeEqualPhrasing::BecameEqualToCurrentBase => "became equal to",
+        }
+    }
+
+    /// Goes between the owner and the attribute, with a trailing space if it isn't empty
+    pub fn base(self) -> &'static str {
+        match self {
+            AttributeEqualPhrasing::SetTo => "",
+            AttributeEqualPhrasing::BecameEqualToBase => "base ",
+            AttributeEqualPhrasing::BecameEqualToCurrentBase => "current base ",
+        }
+    }
+}
+
+/// One sentence of an augment that changes attributes. Events can mix these, e.g.
+/// "X gained +5 Contact. Y lost 3 Aiming. Z's Cunning became equal to their base Intimidation."
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum AttributeOperation<S> {
+    Gain(AttributeChange<S>),
+    /// `amount` is as written, so it's positive for a loss
+    Loss(AttributeChange<S>),
+    Equal {
+        player_name: S,
+        changing_attribute: Attribute,
+        value_attribute: Attribute,
+        /// "their current base" instead of "their base"
+        current: bool,
+    },
+}
+
+impl<S: Clone> AttributeOperation<S> {
+    /// The change this made as a signed amount, so losses are negative. None for equals, since
+    /// those don't say how much the attribute moved.
+    pub fn net_change(&self) -> Option<AttributeChange<S>> {
+        match self {
+            AttributeOperation::Gain(change) => Some(change.clone()),
+            AttributeOperation::Loss(change) => Some(AttributeChange {
+                amount: -change.amount,
+                ..change.clone()
+            }),
+            AttributeOperation::Equal { .. } => None,
+        }
+    }
+}
+
+impl<S> AttributeOperation<S> {
+    pub fn map_text<T>(&self, f: &mut impl FnMut(&S) -> T) -> AttributeOperation<T> {
+        match self {
+            AttributeOperation::Gain(change) => AttributeOperation::Gain(change.map_text(f)),
+            AttributeOperation::Loss(change) => AttributeOperation::Loss(change.map_text(f)),
+            AttributeOperation::Equal {
+                player_name,
+                changing_attribute,
+                value_attribute,
+                current,
+            } => AttributeOperation::Equal {
+                player_name: f(player_name),
+                changing_attribute: *changing_attribute,
+                value_attribute: *value_attribute,
+                current: *current,
+            },
+        }
+    }
+}
+
+/// Totals up the amounts (with multipliers) for each player and attribute. Amounts are taken as
+/// signed, so pass losses through [`AttributeOperation::net_change`] first.
+pub fn aggregate_changes<'a, S: Eq + Hash + Clone + 'a>(
+    changes: impl IntoIterator<Item = &'a AttributeChange<S>>,
+) -> HashMap<(S, Attribute), i32> {
+    let mut totals = HashMap::new();
+    for change in changes {
+        *totals
+            .entry((change.player_name.clone(), change.attribute))
+            .or_default() += change.total();
+    }
+    totals
+}
+
+impl<S: Display> Display for AttributeOperation<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttributeOperation::Gain(change) => write!(f, "{}", change.unparse_gain()),
+            AttributeOperation::Loss(change) => write!(
+                f,
+                "{} lost {} {}.",
+                change.player_name, change.amount, change.attribute
+            ),
+            AttributeOperation::Equal {
+                player_name,
+                changing_attribute,
+                value_attribute,
+                current,
+            } => {
+                let current = if *current { "current " } else { "" };
+                write!(
+                    f,
+                    "{player_name}'s {changing_attribute} became equal to their {current}base {value_attribute}."
+                )
+            }
+        }
+    }
+}
+
+/// One item's worth of a compensatory enchantment that hit several items
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ItemEnchantment<S> {
+    pub player_name: S,
+    pub item: EmojilessItem,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub article: Option<S>,
+    pub enchants: Vec<(u8, Attribute)>,
+    /// The last of `enchants` came from a trailing "and also gained +N Attribute" clause.
+    /// Speculative, see [`crate::team_feed::ParsedTeamFeedEventText::Enchantment`].
+    #[serde(default)]
+    pub also: bool,
+}
+
+impl<S> ItemEnchantment<S> {
+    pub fn map_text<T>(&self, f: &mut impl FnMut(&S) -> T) -> ItemEnchantment<T> {
+        ItemEnchantment {
+            player_name: f(&self.player_name),
+            item: self.item,
+            article: self.article.as_ref().map(&mut *f),
+            enchants: self.enchants.clone(),
+            also: self.also,
+        }
+    }
+}
+
+impl<S: Display> ItemEnchantment<S> {
+    pub(crate) fn unparse_compensatory(&self) -> String {
+        let ItemEnchantment {
+            player_name,
+            item,
+            article,
+            enchants,
+            also,
+        } = self;
+        unparse_enchantment(true, player_name, item, article.as_ref(), enchants, *also)
+    }
+}
+
+/// Every field of the current `Enchantment` variants, in order
+pub(crate) type EnchantmentFields<S> = (
+    S,
+    EmojilessItem,
+    Option<S>,
+    Vec<(u8, Attribute)>,
+    bool,
+    bool,
+);
+
+/// Deserializes the `Enchantment` variant of the team and player feed enums from its current shape
+/// or from either of the shapes [`ParsedFeedEventText`] used to serialize it as:
+/// - `S1Enchantment { player_name, item, amount, attribute }`
+/// - `S2Enchantment { player_name, item, amount, attribute, enchant_two, compensatory }`
+///
+/// so that stored events from before the split still load. The variant names are handled by
+/// aliases on the variants, this only deals with the fields.
+pub(crate) fn deserialize_enchantment<'de, D, S>(
+    deserializer: D,
+) -> Result<EnchantmentFields<S>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    S: Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(bound(deserialize = "S: Deserialize<'de>"))]
+    struct AnyEnchantment<S> {
+        #[serde(alias = "player_name", alias = "team_name")]
+        name: S,
+        item: EmojilessItem,
+        #[serde(default)]
+        article: Option<S>,
+        #[serde(default)]
+        enchants: Option<Vec<(u8, Attribute)>>,
+        #[serde(default)]
+        compensatory: bool,
+        #[serde(default)]
+        also: bool,
+
+        // Only in the old shapes
+        #[serde(default)]
+        amount: Option<u8>,
+        #[serde(default)]
+        attribute: Option<Attribute>,
+        #[serde(default)]
+        enchant_two: Option<(u8, Attribute)>,
+    }
+
+    let enchantment = AnyEnchantment::<S>::deserialize(deserializer)?;
+    let enchants = match (
+        enchantment.enchants,
+        enchantment.amount,
+        enchantment.attribute,
+    ) {
+        (Some(enchants), _, _) => enchants,
+        (None, Some(amount), Some(attribute)) => std::iter::once((amount, attribute))
+            .chain(enchantment.enchant_two)
+            .collect(),
+        (None, _, _) => return Err(serde::de::Error::missing_field("enchants")),
+    };
+
+    Ok((
+        enchantment.name,
+        enchantment.item,
+        enchantment.article,
+        enchants,
+        enchantment.compensatory,
+        enchantment.also,
+    ))
+}
+
+/// The post-season 1 wording of an item or compensatory enchantment
+pub(crate) fn unparse_enchantment(
+    compensatory: bool,
+    name: impl Display,
+    item: &EmojilessItem,
+    article: Option<impl Display>,
+    enchants: &[(u8, Attribute)],
+    also: bool,
+) -> String {
+    let enchant_type = if compensatory { "Compensatory" } else { "Item" };
+    let (enchants, also) = match enchants.split_last() {
+        Some(((amount, attribute), rest)) if also => {
+            (rest, format!(" and also gained +{amount} {attribute}"))
+        }
+        _ => (enchants, String::new()),
+    };
+    let bonuses = enchants
+        .iter()
+        .map(|(amount, attribute)| format!("+{amount} {attribute}"))
+        .join(" and ");
+    if enchants.len() > 1 {
+        let article = article
+            .map(|article| format!("{article} "))
+            .unwrap_or_default();
+        format!("The {enchant_type} Enchantment was a success! {name}'s {item} was enchanted with {article}{bonuses}{also}.")
+    } else {
+        format!("The {enchant_type} Enchantment was a success! {name}'s {item} gained a {bonuses} bonus{also}.")
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
@@ -293,51 +1146,126 @@ pub enum PlayerGreaterAugment {
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct FeedDelivery<S> {
     pub player: S,
+    /// "a" or "an" before the item. None for equips, which don't have one, and treated as "a"
+    /// for anything parsed before this was recorded.
+    #[serde(default = "Option::default", skip_serializing_if = "Option::is_none")]
+    pub article: Option<S>,
     pub item: Item<S>,
+    /// Any items after the first, for deliveries that bring more than one
+    #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
+    pub additional: Vec<Item<S>>,
     pub discarded: Option<Item<S>>,
+    /// The discarded item was moved to storage instead of thrown out.
+    ///
+    /// Speculative: no delivery that moves an item to storage has been captured, so the wording
+    /// this is parsed from is a guess and may never match anything.
+    #[serde(default)]
+    pub discarded_to_storage: bool,
+    /// Why the item was discarded, when the text says. Always None for older events.
+    ///
+    /// Speculative: see [`DiscardReason`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discard_reason: Option<DiscardReason>,
     pub equipped: bool,
 }
+
+/// Which of the delivery-shaped events a [`FeedDelivery`] came from. Displays as the label used
+/// in the event text.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Display, EnumIter)]
+pub enum DeliveryKind {
+    Delivery,
+    Shipment,
+    #[strum(to_string = "Special Delivery")]
+    SpecialDelivery,
+}
+
+/// The clause after a discarded item that says why it was discarded
+///
+/// Speculative: no delivery with a reason clause has been captured, so these wordings are
+/// guesses and may never match anything.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Display, EnumIter)]
+pub enum DiscardReason {
+    #[strum(to_string = "to make room")]
+    InventoryFull,
+    #[strum(to_string = "for the better item")]
+    Replaced,
+}
+impl<S> FeedDelivery<S> {
+    pub fn map_text<T>(&self, f: &mut impl FnMut(&S) -> T) -> FeedDelivery<T> {
+        FeedDelivery {
+            player: f(&self.player),
+            article: self.article.as_ref().map(&mut *f),
+            item: self.item.map_text(f),
+            additional: self
+                .additional
+                .iter()
+                .map(|item| item.map_text(f))
+                .collect(),
+            discarded: self.discarded.as_ref().map(|item| item.map_text(f)),
+            discarded_to_storage: self.discarded_to_storage,
+            discard_reason: self.discard_reason,
+            equipped: self.equipped,
+        }
+    }
+}
+
 impl<S: Display> FeedDelivery<S> {
     pub fn unparse(&self, event: &FeedEvent, delivery_label: &str) -> String {
         let FeedDelivery {
             player,
+            article,
             item,
+            additional,
             discarded,
+            discarded_to_storage,
+            discard_reason,
             equipped,
         } = self;
 
         let discarded = match discarded {
             Some(discarded) => {
-                let verb = if Breakpoints::Season5TenseChange.before(
+                let past_tense = Breakpoints::Season5TenseChange.before(
                     event.season as u32,
                     event.day.as_ref().ok().copied(),
                     None,
-                ) {
-                    "discarded"
-                } else {
-                    "discard"
-                };
+                );
 
-                format!(" They {verb} their {discarded}.")
+                let reason = discard_reason.map(|r| format!(" {r}")).unwrap_or_default();
+                if *discarded_to_storage {
+                    let verb = if past_tense { "moved" } else { "move" };
+                    format!(" They {verb} their {discarded} to storage{reason}.")
+                } else {
+                    let verb = if past_tense { "discarded" } else { "discard" };
+                    format!(" They {verb} their {discarded}{reason}.")
+                }
             }
             None => String::new(),
         };
 
+        let article = article
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_else(|| "a".to_string());
         let verb = if *equipped {
-            "equips"
+            "equips".to_string()
         } else if Breakpoints::Season5TenseChange.before(
             event.season as u32,
             event.day.as_ref().ok().copied(),
             None,
         ) {
-            "received a"
+            format!("received {article}")
         } else {
-            "receives a"
+            format!("receives {article}")
         };
 
         let from = if *equipped { "from " } else { "" };
+        let conjunction = if *equipped { " and " } else { " and a " };
+        let additional = additional
+            .iter()
+            .map(|item| format!("{conjunction}{item}"))
+            .collect::<String>();
 
-        format!("{player} {verb} {item} {from}{delivery_label}.{discarded}")
+        format!("{player} {verb} {item}{additional} {from}{delivery_label}.{discarded}")
     }
 }
 
@@ -347,6 +1275,25 @@ pub struct EmojilessItem {
     pub item: ItemName,
     pub suffix: Option<ItemSuffix>,
 }
+impl EmojilessItem {
+    pub fn with_emoji<S>(self, emoji: S) -> Item<S> {
+        let affixes = if self.prefix.is_none() && self.suffix.is_none() {
+            ItemAffixes::None
+        } else {
+            ItemAffixes::PrefixSuffix(
+                self.prefix.into_iter().collect(),
+                self.suffix.into_iter().collect(),
+            )
+        };
+
+        Item {
+            item_emoji: emoji,
+            item: self.item,
+            affixes,
+        }
+    }
+}
+
 impl Display for EmojilessItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let EmojilessItem {
@@ -366,3 +1313,451 @@ impl Display for EmojilessItem {
         write!(f, "{prefix}{item}{suffix}")
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::{borrow::Cow, path::Path};
+
+    use crate::{
+        enums::{Attribute, FeedEventSource, FeedEventType, ItemName, ItemPrefix, ItemSuffix},
+        feed_event::{
+            aggregate_changes, AttributeChange, AttributeOperation, EmojilessItem, FeedDelivery,
+            FeedErrorCode, FeedEvent, FeedEventParseError, FeedEventView, InternallyTagged,
+            ParsedFeedEventText,
+        },
+        parsed_event::{Item, ItemAffixes},
+        utils::feed_event,
+        NotRecognized,
+    };
+
+    #[test]
+    fn aggregate_gains_and_losses() {
+        let change = |player_name, amount, attribute| AttributeChange {
+            player_name,
+            amount,
+            attribute,
+            multiplier: None,
+        };
+        let operations = [
+            AttributeOperation::Gain(change("Yumi Pratt", 5, Attribute::Contact)),
+            AttributeOperation::Loss(change("Yumi Pratt", 3, Attribute::Contact)),
+            AttributeOperation::Gain(change("Yumi Pratt", 10, Attribute::Contact)),
+            AttributeOperation::Loss(change("Kelly Vieira", 4, Attribute::Contact)),
+            AttributeOperation::Gain(change("Yumi Pratt", 2, Attribute::Aiming)),
+            AttributeOperation::Equal {
+                player_name: "Yumi Pratt",
+                changing_attribute: Attribute::Aiming,
+                value_attribute: Attribute::Contact,
+                current: false,
+            },
+        ];
+        let changes = operations
+            .iter()
+            .filter_map(AttributeOperation::net_change)
+            .collect::<Vec<_>>();
+
+        let totals = aggregate_changes(&changes);
+        assert_eq!(totals.len(), 3);
+        assert_eq!(totals[&("Yumi Pratt", Attribute::Contact)], 12);
+        assert_eq!(totals[&("Kelly Vieira", Attribute::Contact)], -4);
+        assert_eq!(totals[&("Yumi Pratt", Attribute::Aiming)], 2);
+    }
+
+    #[test]
+    fn error_codes() {
+        let error = FeedEventParseError::EventTypeNotRecognized(NotRecognized("party".into()));
+        assert_eq!(error.code(), FeedErrorCode::UnrecognizedEventType);
+        assert_eq!(
+            error.to_string(),
+            "feed event type \"party\" not recognized"
+        );
+
+        let error = FeedEventParseError::FailedParsingText {
+            event_type: FeedEventType::Game,
+            text: "Something new.".to_string(),
+            consumed: 0,
+        };
+        assert_eq!(error.code(), FeedErrorCode::FailedText);
+
+        assert_eq!(
+            FeedEventParseError::EmptyText.code(),
+            FeedErrorCode::EmptyText
+        );
+    }
+
+    #[test]
+    fn display_matches_unparse() -> Result<(), Box<dyn std::error::Error>> {
+        let event: FeedEvent = serde_json::from_str(&std::fs::read_to_string(Path::new(
+            "test_data/s2_feed_event.json",
+        ))?)?;
+        let parsed = ParsedFeedEventText::Delivery {
+            delivery: FeedDelivery {
+                player: "Yumi Pratt",
+                article: Some("a"),
+                item: Item {
+                    item_emoji: "🧢",
+                    item: ItemName::Cap,
+                    affixes: ItemAffixes::None,
+                },
+                additional: Vec::new(),
+                discarded: None,
+                discarded_to_storage: false,
+                discard_reason: None,
+                equipped: false,
+            },
+        };
+
+        for source in [FeedEventSource::Player, FeedEventSource::Team] {
+            assert_eq!(
+                parsed.display(&event, source).to_string(),
+                parsed.unparse(&event, source)
+            );
+        }
+        assert_eq!(
+            format!("{}", parsed.display(&event, FeedEventSource::Player)),
+            event.text
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_error_unparse_borrows() -> Result<(), Box<dyn std::error::Error>> {
+        let event: FeedEvent = serde_json::from_str(&std::fs::read_to_string(Path::new(
+            "test_data/s2_feed_event.json",
+        ))?)?;
+        let parsed = ParsedFeedEventText::ParseError {
+            error: FeedEventParseError::FailedParsingText {
+                event_type: FeedEventType::Game,
+                text: event.text.clone(),
+                consumed: 0,
+            },
+            text: event.text.as_str(),
+        };
+
+        let unparsed = parsed.unparse_cow(&event, FeedEventSource::Player);
+        assert!(matches!(unparsed, Cow::Borrowed(_)));
+        assert_eq!(unparsed, event.text);
+
+        Ok(())
+    }
+
+    #[test]
+    fn unparse_inferred_source() -> Result<(), Box<dyn std::error::Error>> {
+        let mut event: FeedEvent = serde_json::from_str(&std::fs::read_to_string(Path::new(
+            "test_data/s2_feed_event.json",
+        ))?)?;
+        let parsed = ParsedFeedEventText::SingleAttributeEquals {
+            player_name: "Yumi Pratt",
+            changing_attribute: Attribute::Contact,
+            value_attribute: Attribute::Aiming,
+        };
+
+        // No recorded source, so the fallback is used
+        assert_eq!(
+            parsed.unparse_inferred(&event, FeedEventSource::Team),
+            parsed.unparse(&event, FeedEventSource::Team)
+        );
+
+        event.extra_fields.insert(
+            "legacy_source".to_string(),
+            serde_json::json!({ "collection": "player" }),
+        );
+        assert_eq!(
+            parsed.unparse_inferred(&event, FeedEventSource::Team),
+            "Yumi Pratt's Contact was set to their Aiming."
+        );
+
+        event.extra_fields["legacy_source"]["collection"] = "team".into();
+        assert_eq!(
+            parsed.unparse_inferred(&event, FeedEventSource::Player),
+            "Yumi Pratt's Contact became equal to their base Aiming."
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn attribute_equals_phrasing_matrix() -> Result<(), Box<dyn std::error::Error>> {
+        let parsed = ParsedFeedEventText::SingleAttributeEquals {
+            player_name: "Yumi Pratt",
+            changing_attribute: Attribute::Contact,
+            value_attribute: Attribute::Aiming,
+        };
+        let set_to = "Yumi Pratt's Contact was set to their Aiming.";
+        let base = "Yumi Pratt's Contact became equal to their base Aiming.";
+        let current_base = "Yumi Pratt's Contact became equal to their current base Aiming.";
+
+        // (season, day, player feed, team feed). S1AttributeEqualChange is season 1 day 215.
+        let cases = [
+            (1, 100, set_to, base),
+            (1, 214, set_to, base),
+            (1, 215, current_base, current_base),
+            (2, 10, current_base, current_base),
+            (3, 1, set_to, set_to),
+            (5, 100, set_to, set_to),
+        ];
+        for (season, day, player, team) in cases {
+            let event = feed_event(season, day, "augment", "");
+            assert_eq!(
+                parsed.unparse(&event, FeedEventSource::Player),
+                player,
+                "player feed, season {season} day {day}"
+            );
+            assert_eq!(
+                parsed.unparse(&event, FeedEventSource::Team),
+                team,
+                "team feed, season {season} day {day}"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn view_borrows_attribute_changes() {
+        let parsed = ParsedFeedEventText::AttributeChanges {
+            changes: vec![
+                AttributeChange {
+                    player_name: String::from("Yumi Pratt"),
+                    amount: 5,
+                    attribute: Attribute::Contact,
+                    multiplier: None,
+                },
+                AttributeChange {
+                    player_name: String::from("Kelly Vieira"),
+                    amount: 3,
+                    attribute: Attribute::Aiming,
+                    multiplier: None,
+                },
+            ],
+        };
+
+        let FeedEventView::AttributeChanges { changes } = parsed.view() else {
+            panic!("expected AttributeChanges, got {:?}", parsed.view());
+        };
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].player_name, "Yumi Pratt");
+        assert_eq!(changes[1].attribute, Attribute::Aiming);
+        // The view points into the original event rather than a copy of it
+        let ParsedFeedEventText::AttributeChanges { changes: owned } = &parsed else {
+            unreachable!()
+        };
+        assert!(std::ptr::eq(changes, owned.as_slice()));
+    }
+
+    #[test]
+    fn enchantment_bonuses() {
+        let enchantment = |enchant_two| ParsedFeedEventText::S2Enchantment {
+            player_name: "Yumi Pratt",
+            item: EmojilessItem {
+                prefix: None,
+                item: ItemName::Cap,
+                suffix: None,
+            },
+            amount: 12,
+            attribute: Attribute::Contact,
+            enchant_two,
+            compensatory: false,
+        };
+
+        let one = enchantment(None);
+        assert_eq!(one.second_amount(), None);
+        assert_eq!(one.second_attribute(), None);
+        assert_eq!(one.all_bonuses(), vec![(12, Attribute::Contact)]);
+
+        let two = enchantment(Some((3, Attribute::Aiming)));
+        assert_eq!(two.second_amount(), Some(3));
+        assert_eq!(two.second_attribute(), Some(Attribute::Aiming));
+        assert_eq!(
+            two.all_bonuses(),
+            vec![(12, Attribute::Contact), (3, Attribute::Aiming)]
+        );
+        let total: u32 = two
+            .all_bonuses()
+            .iter()
+            .map(|(amount, _)| u32::from(*amount))
+            .sum();
+        assert_eq!(total, 15);
+
+        let not_an_enchantment = ParsedFeedEventText::Released { team: "Crabs" };
+        assert_eq!(not_an_enchantment.all_bonuses(), vec![]);
+    }
+
+    #[test]
+    fn emojiless_item_round_trip() {
+        let items = [
+            EmojilessItem {
+                prefix: None,
+                item: ItemName::Cap,
+                suffix: None,
+            },
+            EmojilessItem {
+                prefix: Some(ItemPrefix::Sharp),
+                item: ItemName::Gloves,
+                suffix: None,
+            },
+            EmojilessItem {
+                prefix: Some(ItemPrefix::Clever),
+                item: ItemName::TShirt,
+                suffix: Some(ItemSuffix::Cat),
+            },
+        ];
+
+        for emojiless in items {
+            let item = emojiless.with_emoji("🧢");
+            assert_eq!(item.item_emoji, "🧢");
+            assert_eq!(item.to_string(), format!("🧢 {emojiless}"));
+            assert_eq!(item.without_emoji(), Some(emojiless));
+        }
+
+        let rare = Item {
+            item_emoji: "🧢",
+            item: ItemName::Cap,
+            affixes: ItemAffixes::RareName("Fancy"),
+        };
+        assert_eq!(rare.without_emoji(), None);
+
+        let empty_affixes = Item {
+            item_emoji: "🧢",
+            item: ItemName::Cap,
+            affixes: ItemAffixes::PrefixSuffix(vec![], vec![]),
+        };
+        assert_eq!(empty_affixes.without_emoji(), None);
+    }
+
+    #[cfg(feature = "miette")]
+    #[test]
+    fn failed_parsing_diagnostic() {
+        use miette::Diagnostic;
+
+        let text = "Yumi Pratt received a 🧢 Cap Delivery. Something else";
+        let consumed = text.find(" Something").unwrap();
+        let error = FeedEventParseError::FailedParsingText {
+            event_type: FeedEventType::Game,
+            text: text.to_string(),
+            consumed,
+        };
+
+        let labels = error.labels().unwrap().collect::<Vec<_>>();
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].offset(), consumed);
+        assert_eq!(labels[0].len(), " Something else".len());
+        assert_eq!(
+            miette::Diagnostic::code(&error).unwrap().to_string(),
+            "mmolb_parsing::feed_event::failed_parsing_text"
+        );
+
+        let mut rendered = String::new();
+        miette::NarratableReportHandler::new()
+            .render_report(&mut rendered, &error)
+            .unwrap();
+        assert!(rendered.contains("couldn't parse this"), "{rendered}");
+    }
+
+    #[test]
+    fn internally_tagged_round_trip() {
+        for enchant_two in [None, Some((5, Attribute::Aiming))] {
+            let parsed = ParsedFeedEventText::S2Enchantment {
+                player_name: "Yumi Pratt".to_string(),
+                item: EmojilessItem {
+                    prefix: Some(ItemPrefix::Sharp),
+                    item: ItemName::Cap,
+                    suffix: None,
+                },
+                amount: 12,
+                attribute: Attribute::Contact,
+                enchant_two,
+                compensatory: false,
+            };
+
+            let json = serde_json::to_value(InternallyTagged(&parsed)).unwrap();
+            assert_eq!(json["type"], "S2Enchantment");
+            assert_eq!(json["player_name"], "Yumi Pratt");
+            assert_eq!(json["amount"], 12);
+            assert!(json.get("S2Enchantment").is_none());
+
+            let InternallyTagged(round_trip) =
+                serde_json::from_value::<InternallyTagged<ParsedFeedEventText<String>>>(json)
+                    .unwrap();
+            assert_eq!(round_trip, parsed);
+        }
+    }
+
+    #[test]
+    fn legacy_enchantments_load() {
+        use crate::{player_feed::ParsedPlayerFeedEventText, team_feed::ParsedTeamFeedEventText};
+
+        let item = EmojilessItem {
+            prefix: Some(ItemPrefix::Sharp),
+            item: ItemName::Cap,
+            suffix: None,
+        };
+        let s1 = serde_json::json!({
+            "S1Enchantment": {
+                "player_name": "Yumi Pratt",
+                "item": item,
+                "amount": 12,
+                "attribute": "Contact",
+            }
+        });
+        let s2 = serde_json::to_value(ParsedFeedEventText::S2Enchantment {
+            player_name: "Yumi Pratt".to_string(),
+            item,
+            amount: 12,
+            attribute: Attribute::Contact,
+            enchant_two: Some((5, Attribute::Aiming)),
+            compensatory: true,
+        })
+        .unwrap();
+
+        assert_eq!(
+            serde_json::from_value::<ParsedPlayerFeedEventText<String>>(s1.clone()).unwrap(),
+            ParsedPlayerFeedEventText::Enchantment {
+                player_name: "Yumi Pratt".to_string(),
+                item,
+                article: None,
+                enchants: vec![(12, Attribute::Contact)],
+                compensatory: false,
+                also: false,
+            }
+        );
+        assert_eq!(
+            serde_json::from_value::<ParsedTeamFeedEventText<String>>(s1).unwrap(),
+            ParsedTeamFeedEventText::Enchantment {
+                team_name: "Yumi Pratt".to_string(),
+                item,
+                article: None,
+                enchants: vec![(12, Attribute::Contact)],
+                compensatory: false,
+                also: false,
+            }
+        );
+        assert_eq!(
+            serde_json::from_value::<ParsedPlayerFeedEventText<String>>(s2).unwrap(),
+            ParsedPlayerFeedEventText::Enchantment {
+                player_name: "Yumi Pratt".to_string(),
+                item,
+                article: None,
+                enchants: vec![(12, Attribute::Contact), (5, Attribute::Aiming)],
+                compensatory: true,
+                also: false,
+            }
+        );
+
+        // The current shape still round trips
+        let current = ParsedPlayerFeedEventText::Enchantment {
+            player_name: "Yumi Pratt".to_string(),
+            item,
+            article: Some("a".to_string()),
+            enchants: vec![(12, Attribute::Contact), (3, Attribute::Lift)],
+            compensatory: false,
+            also: true,
+        };
+        let json = serde_json::to_value(&current).unwrap();
+        assert_eq!(
+            serde_json::from_value::<ParsedPlayerFeedEventText<String>>(json).unwrap(),
+            current
+        );
+    }
+}
@@ -1,8 +1,23 @@
 mod feed_event;
 mod feed_event_text;
+mod intern;
+mod ndjson;
+mod registry;
+mod stats;
 
 pub use feed_event::{FeedEvent, FeedFallingStarOutcome};
 pub use feed_event_text::{
-    AttributeChange, EmojilessItem, FeedDelivery, FeedEventParseError, GreaterAugment,
-    ParsedFeedEventText, PlayerGreaterAugment,
+    aggregate_changes, AttributeChange, AttributeEqualPhrasing, AttributeOperation, AttributeSet,
+    DeliveryKind, DiscardReason, EmojilessItem, FeedDelivery, FeedErrorCode, FeedEventDisplay,
+    FeedEventParseError, FeedEventView, GreaterAugment, InternallyTagged, ItemEnchantment,
+    ItemModChange, ItemModification, ParseOptions, ParsedFeedEventText, PlayerGreaterAugment,
 };
+pub use intern::StringInterner;
+pub use ndjson::FeedNdjsonError;
+pub use registry::{CustomParser, ParserRegistry};
+pub use stats::{
+    classify_failures, parse_stats, ClosestParser, FailureGroup, ParseStats, TypeParseStats,
+};
+
+pub(crate) use feed_event_text::{deserialize_enchantment, unparse_enchantment};
+pub(crate) use ndjson::parse_ndjson;
@@ -0,0 +1,41 @@
+use std::io::BufRead;
+
+use thiserror::Error;
+
+use crate::feed_event::FeedEvent;
+
+/// Something went wrong before a line of a newline-delimited dump could be parsed. Text that
+/// doesn't parse is not an error here, it comes back as the usual `ParseError` variant.
+#[derive(Debug, Error)]
+pub enum FeedNdjsonError {
+    #[error("couldn't read line {line}: {source}")]
+    Io {
+        line: usize,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("line {line} isn't a feed event: {source}")]
+    Deserialize {
+        line: usize,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+/// Lazily deserializes and parses each non-empty line of `reader`. Line numbers start at 1.
+pub(crate) fn parse_ndjson<R: BufRead, O>(
+    reader: R,
+    parse: impl Fn(&FeedEvent) -> O,
+) -> impl Iterator<Item = Result<O, FeedNdjsonError>> {
+    reader
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line))
+        .filter(|(_, line)| !matches!(line, Ok(line) if line.trim().is_empty()))
+        .map(move |(line, text)| {
+            let text = text.map_err(|source| FeedNdjsonError::Io { line, source })?;
+            let event: FeedEvent = serde_json::from_str(&text)
+                .map_err(|source| FeedNdjsonError::Deserialize { line, source })?;
+            Ok(parse(&event))
+        })
+}
@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+
+use crate::enums::FeedEventType;
+
+/// Gets the event text and returns None if it doesn't recognize it
+pub type CustomParser<P> = Box<dyn Fn(&str) -> Option<P>>;
+
+/// Extra parsers to try before the built-in ones, for wordings this crate doesn't know about yet.
+/// `P` is the parsed output, e.g. `ParsedTeamFeedEventText<String>`. See
+/// [`crate::team_feed::parse_team_feed_event_with_registry`] and
+/// [`crate::player_feed::parse_player_feed_event_with_registry`].
+pub struct ParserRegistry<P> {
+    parsers: HashMap<FeedEventType, Vec<CustomParser<P>>>,
+}
+
+impl<P> Default for ParserRegistry<P> {
+    fn default() -> Self {
+        Self {
+            parsers: HashMap::new(),
+        }
+    }
+}
+
+impl<P> ParserRegistry<P> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Custom parsers for the same event type are tried in the order they were registered. The
+    /// first one to return Some wins.
+    pub fn register(&mut self, event_type: FeedEventType, parser: CustomParser<P>) {
+        self.parsers.entry(event_type).or_default().push(parser);
+    }
+
+    pub(crate) fn parse(&self, event_type: FeedEventType, text: &str) -> Option<P> {
+        self.parsers
+            .get(&event_type)?
+            .iter()
+            .find_map(|parser| parser(text))
+    }
+}
@@ -0,0 +1,227 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    enums::{FeedEventSource, FeedEventType},
+    feed_event::{FeedEvent, FeedEventParseError},
+    player_feed::{parse_player_feed_event, ParsedPlayerFeedEventText},
+    team_feed::{parse_team_feed_event, ParsedTeamFeedEventText},
+};
+
+/// How much of a feed parsed, for keeping an eye on ingestion.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParseStats {
+    pub total: usize,
+    pub parsed: usize,
+    pub event_type_not_recognized: usize,
+    pub failed_parsing_text: usize,
+    pub empty_text: usize,
+    pub text_too_long: usize,
+    /// Only events with a recognized type show up here
+    pub by_type: BTreeMap<FeedEventType, TypeParseStats>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TypeParseStats {
+    pub total: usize,
+    pub parsed: usize,
+}
+
+impl ParseStats {
+    fn record(&mut self, event: &FeedEvent, error: Option<&FeedEventParseError>) {
+        self.total += 1;
+        match error {
+            None => self.parsed += 1,
+            Some(FeedEventParseError::EventTypeNotRecognized(_)) => {
+                self.event_type_not_recognized += 1
+            }
+            Some(FeedEventParseError::FailedParsingText { .. }) => self.failed_parsing_text += 1,
+            Some(FeedEventParseError::EmptyText) => self.empty_text += 1,
+            Some(FeedEventParseError::TextTooLong { .. }) => self.text_too_long += 1,
+        }
+
+        if let Ok(event_type) = event.event_type {
+            let by_type = self.by_type.entry(event_type).or_default();
+            by_type.total += 1;
+            if error.is_none() {
+                by_type.parsed += 1;
+            }
+        }
+    }
+}
+
+/// Parses every event in a feed and tallies up how it went.
+pub fn parse_stats(events: &[FeedEvent], source: FeedEventSource) -> ParseStats {
+    let mut stats = ParseStats::default();
+    for event in events {
+        match source {
+            FeedEventSource::Team => match parse_team_feed_event(event) {
+                ParsedTeamFeedEventText::ParseError { error, .. } => {
+                    stats.record(event, Some(&error))
+                }
+                _ => stats.record(event, None),
+            },
+            FeedEventSource::Player => match parse_player_feed_event(event) {
+                ParsedPlayerFeedEventText::ParseError { error, .. } => {
+                    stats.record(event, Some(&error))
+                }
+                _ => stats.record(event, None),
+            },
+        }
+    }
+    stats
+}
+
+/// A rough guess at which parser a failed text was meant for, going by the words in it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ClosestParser {
+    GameResult,
+    Delivery,
+    Enchantment,
+    AttributeChange,
+    Modification,
+    Unknown,
+}
+
+impl ClosestParser {
+    /// Checked in order, so e.g. an enchantment that gained a bonus counts as an enchantment
+    const HINTS: &[(ClosestParser, &[&str])] = &[
+        (ClosestParser::GameResult, &[" - FINAL "]),
+        (
+            ClosestParser::Delivery,
+            &[
+                "Delivery",
+                "Shipment",
+                " received a ",
+                " receives a ",
+                " equips ",
+            ],
+        ),
+        (ClosestParser::Enchantment, &["Enchantment", "enchanted"]),
+        (
+            ClosestParser::AttributeChange,
+            &[" gained +", " lost ", " was set to ", " became equal to "],
+        ),
+        (ClosestParser::Modification, &["Modification"]),
+    ];
+
+    pub fn guess(text: &str) -> Self {
+        Self::HINTS
+            .iter()
+            .find(|(_, hints)| hints.iter().any(|hint| text.contains(hint)))
+            .map(|(parser, _)| *parser)
+            .unwrap_or(ClosestParser::Unknown)
+    }
+}
+
+/// Every text that failed to parse which looks like it was meant for `closest`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailureGroup {
+    pub closest: ClosestParser,
+    pub texts: Vec<String>,
+}
+
+/// Groups the texts that failed to parse by which parser they look closest to, to help decide
+/// which parser to work on next. Groups are in `ClosestParser` order and texts are in the order
+/// they appeared in `events`.
+pub fn classify_failures(events: &[FeedEvent], source: FeedEventSource) -> Vec<FailureGroup> {
+    let mut groups = BTreeMap::<ClosestParser, Vec<String>>::new();
+    for event in events {
+        let error = match source {
+            FeedEventSource::Team => match parse_team_feed_event(event) {
+                ParsedTeamFeedEventText::ParseError { error, .. } => Some(error),
+                _ => None,
+            },
+            FeedEventSource::Player => match parse_player_feed_event(event) {
+                ParsedPlayerFeedEventText::ParseError { error, .. } => Some(error),
+                _ => None,
+            },
+        };
+        if let Some(FeedEventParseError::FailedParsingText { text, .. }) = error {
+            groups
+                .entry(ClosestParser::guess(&text))
+                .or_default()
+                .push(text);
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|(closest, texts)| FailureGroup { closest, texts })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::enums::{FeedEventSource, FeedEventType};
+    use crate::utils::feed_event;
+
+    use super::{classify_failures, parse_stats, ClosestParser, FailureGroup, TypeParseStats};
+
+    #[test]
+    fn tallies() {
+        let events = [
+            ("game", "🦀 Crabs vs. 🐝 Bees - FINAL 3-7"),
+            ("game", "Something nobody has seen before."),
+            ("some new type", "Something else nobody has seen before."),
+        ]
+        .map(|(event_type, text)| feed_event(4, 12, event_type, text));
+
+        let stats = parse_stats(&events, FeedEventSource::Team);
+        assert_eq!(stats.total, 3);
+        assert_eq!(stats.parsed, 1);
+        assert_eq!(stats.failed_parsing_text, 1);
+        assert_eq!(stats.event_type_not_recognized, 1);
+        assert_eq!(stats.by_type.len(), 1);
+        assert_eq!(
+            stats.by_type[&FeedEventType::Game],
+            TypeParseStats {
+                total: 2,
+                parsed: 1
+            }
+        );
+    }
+
+    #[test]
+    fn classifies_failures() {
+        let events = [
+            ("game", "🦀 Crabs vs. 🐝 Bees - FINAL three-seven"),
+            ("augment", "Yumi Pratt gained +3 Vibes."),
+            (
+                "augment",
+                "The Item Enchantment was a success! Yumi Pratt's Cap glowed.",
+            ),
+            ("augment", "Kelly Vieira gained +2 Sparkle."),
+            ("augment", "Something nobody has seen before."),
+            // Parses fine, so it isn't in any group
+            ("game", "🦀 Crabs vs. 🐝 Bees - FINAL 3-7"),
+        ]
+        .map(|(event_type, text)| feed_event(4, 12, event_type, text));
+
+        assert_eq!(
+            classify_failures(&events, FeedEventSource::Team),
+            [
+                FailureGroup {
+                    closest: ClosestParser::GameResult,
+                    texts: vec!["🦀 Crabs vs. 🐝 Bees - FINAL three-seven".to_string()],
+                },
+                FailureGroup {
+                    closest: ClosestParser::Enchantment,
+                    texts: vec![
+                        "The Item Enchantment was a success! Yumi Pratt's Cap glowed.".to_string()
+                    ],
+                },
+                FailureGroup {
+                    closest: ClosestParser::AttributeChange,
+                    texts: vec![
+                        "Yumi Pratt gained +3 Vibes.".to_string(),
+                        "Kelly Vieira gained +2 Sparkle.".to_string(),
+                    ],
+                },
+                FailureGroup {
+                    closest: ClosestParser::Unknown,
+                    texts: vec!["Something nobody has seen before.".to_string()],
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,192 @@
+//! Throws randomly generated events at the conversions and feed parsers and checks that nothing
+//! panics. Runs a few hundred cases with `cargo test`. Set `MMOLB_FUZZ_ITERATIONS` to run more,
+//! which is worth doing after adding a new parser.
+
+use arbitrary::{Result, Unstructured};
+
+use crate::{
+    feed_event::FeedEvent,
+    game::{event::RawEvent, Event},
+    player_feed::parse_player_feed_event,
+    team_feed::parse_team_feed_event,
+};
+
+const FEED_EVENT_TYPES: &[&str] = &[
+    "game",
+    "augment",
+    "release",
+    "season",
+    "lottery",
+    "maintenance",
+    "roster",
+    "election",
+    "weather",
+    "something new",
+];
+
+/// Bits of real feed text, so the generated text gets further into the parsers than pure noise
+const FRAGMENTS: &[&str] = &[
+    "Yumi Pratt",
+    "🦀 Crabs",
+    "🐝 Bees",
+    " vs. ",
+    " - FINAL ",
+    "-",
+    " received a ",
+    " receives a ",
+    " equips ",
+    "🧢 Cap",
+    "Amplification Orb",
+    " of the Acrobat",
+    " Delivery",
+    " Shipment",
+    ". They discard their ",
+    " to storage",
+    "The Item Enchantment was a success! ",
+    "The Compensatory Enchantment was a success! ",
+    "'s ",
+    " was enchanted with ",
+    "a ",
+    "+",
+    " and ",
+    " gained ",
+    " Contact",
+    " bonus",
+    " to ",
+    "All players",
+    "Season ",
+    " has begun",
+    " lost ",
+    " durability for playing in Season ",
+    " retired from MMOLB!",
+    ".",
+    "!",
+    " ",
+    "  ",
+];
+
+const NUMBERS: &[&str] = &[
+    "0",
+    "1",
+    "7",
+    "255",
+    "256",
+    "32767",
+    "65536",
+    "-1",
+    "99999999999",
+];
+
+fn text(u: &mut Unstructured) -> Result<String> {
+    let mut text = String::new();
+    for _ in 0..u.int_in_range(0..=12)? {
+        if u.ratio(1, 4)? {
+            text.push_str(u.choose(NUMBERS)?);
+        } else {
+            text.push_str(u.choose(FRAGMENTS)?);
+        }
+    }
+    Ok(text)
+}
+
+fn maybe_u8(u: &mut Unstructured) -> Result<serde_json::Value> {
+    Ok(if u.arbitrary()? {
+        serde_json::Value::Null
+    } else {
+        u.arbitrary::<u8>()?.into()
+    })
+}
+
+fn raw_event(u: &mut Unstructured) -> Result<serde_json::Value> {
+    let player = |u: &mut Unstructured| -> Result<serde_json::Value> {
+        Ok(match u.int_in_range(0..=2)? {
+            0 => serde_json::Value::Null,
+            1 => "".into(),
+            _ => "Yumi Pratt".into(),
+        })
+    };
+
+    Ok(serde_json::json!({
+        "inning": u.arbitrary::<u8>()?,
+        "inning_side": u.int_in_range(0..=4u8)?,
+        "away_score": u.arbitrary::<u16>()?,
+        "home_score": u.arbitrary::<u16>()?,
+        "balls": maybe_u8(u)?,
+        "strikes": maybe_u8(u)?,
+        "outs": maybe_u8(u)?,
+        "on_1b": u.arbitrary::<bool>()?,
+        "on_2b": u.arbitrary::<bool>()?,
+        "on_3b": u.arbitrary::<bool>()?,
+        "on_deck": player(u)?,
+        "batter": player(u)?,
+        "pitcher": player(u)?,
+        "pitch_info": *u.choose(&["", "93.2 MPH Fastball", "MPH", "1000000 MPH Sinker"])?,
+        "zone": maybe_u8(u)?,
+        "event": *u.choose(&["Pitch", "NowBatting", "SomethingNew"])?,
+        "message": text(u)?,
+        "index": u.arbitrary::<u16>()?,
+    }))
+}
+
+fn feed_event(u: &mut Unstructured) -> Result<serde_json::Value> {
+    Ok(serde_json::json!({
+        "emoji": "📦",
+        "season": u.int_in_range(0..=12u8)?,
+        "day": u.int_in_range(0..=300u16)?,
+        "status": "Regular Season",
+        "type": *u.choose(FEED_EVENT_TYPES)?,
+        "text": text(u)?,
+        "ts": "2025-07-20T12:00:00.000000+00:00",
+        "links": [],
+    }))
+}
+
+fn iterations() -> u64 {
+    std::env::var("MMOLB_FUZZ_ITERATIONS")
+        .ok()
+        .and_then(|n| n.parse().ok())
+        .unwrap_or(500)
+}
+
+/// Deterministic bytes for `Unstructured`, so a failure can be reproduced from its seed
+fn bytes(seed: u64) -> Vec<u8> {
+    let mut state = seed
+        .wrapping_mul(6364136223846793005)
+        .wrapping_add(1442695040888963407);
+    (0..512)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 33) as u8
+        })
+        .collect()
+}
+
+#[test]
+fn raw_event_conversions_dont_panic() {
+    for seed in 0..iterations() {
+        let bytes = bytes(seed);
+        let value = raw_event(&mut Unstructured::new(&bytes)).expect("enough bytes");
+        let raw: RawEvent = serde_json::from_value(value)
+            .unwrap_or_else(|e| panic!("seed {seed} didn't deserialize: {e}"));
+
+        let event = Event::from(raw);
+        let _ = event.count();
+        let _ = event.leader();
+        let _ = RawEvent::from(event);
+    }
+}
+
+#[test]
+fn feed_parsers_dont_panic() {
+    for seed in 0..iterations() {
+        let bytes = bytes(seed);
+        let value = feed_event(&mut Unstructured::new(&bytes)).expect("enough bytes");
+        let event: FeedEvent = serde_json::from_value(value)
+            .unwrap_or_else(|e| panic!("seed {seed} didn't deserialize: {e}"));
+
+        let _ = parse_team_feed_event(&event).unparse(&event);
+        let _ = parse_player_feed_event(&event).unparse(&event);
+    }
+}
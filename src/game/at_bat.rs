@@ -0,0 +1,132 @@
+use crate::{
+    enums::{EventType, Inning},
+    game::Event,
+    utils::MaybeRecognizedResult,
+};
+
+/// One batter's consecutive events within an inning
+#[derive(Debug, Clone, PartialEq)]
+pub struct AtBat<'a> {
+    pub batter: &'a str,
+    pub inning: Inning,
+    /// Every event while this batter was up, including ones without a pitch (like a mound visit)
+    pub events: &'a [Event],
+}
+
+impl<'a> AtBat<'a> {
+    /// Just the events that had a pitch
+    pub fn pitches(&self) -> impl Iterator<Item = &'a Event> + use<'a> {
+        self.events.iter().filter(|event| event.pitch.is_some())
+    }
+
+    /// The type of the last event in the at-bat. This is usually a `Pitch`, but an at-bat can
+    /// also be cut short, e.g. by the game ending.
+    pub fn end(&self) -> &'a MaybeRecognizedResult<EventType> {
+        // Never empty, so there's always a last event
+        &self.events[self.events.len() - 1].event
+    }
+}
+
+/// Splits a game's events into at-bats. A new at-bat starts whenever the batter or the inning
+/// changes. Events with no batter, like the pregame and between-inning events, aren't part of
+/// any at-bat and are skipped.
+pub fn group_into_at_bats(events: &[Event]) -> Vec<AtBat<'_>> {
+    let mut at_bats = Vec::new();
+    let mut start = 0;
+
+    while start < events.len() {
+        let Some(batter) = events[start].batter.current_name() else {
+            start += 1;
+            continue;
+        };
+        let inning = events[start].inning;
+
+        let len = events[start..]
+            .iter()
+            .take_while(|event| {
+                event.inning == inning && event.batter.current_name() == Some(batter)
+            })
+            .count();
+
+        at_bats.push(AtBat {
+            batter,
+            inning,
+            events: &events[start..start + len],
+        });
+        start += len;
+    }
+
+    at_bats
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        enums::{EventType, Inning, TopBottom},
+        game::Event,
+        utils::game_event,
+    };
+
+    use super::group_into_at_bats;
+
+    fn event(index: u16, event: &str, batter: &str, pitch_info: &str, message: &str) -> Event {
+        game_event(serde_json::json!({
+            "batter": batter,
+            "event": event,
+            "index": index,
+            "message": message,
+            "on_deck": "",
+            "pitch_info": pitch_info,
+        }))
+    }
+
+    #[test]
+    fn two_at_bats_in_an_inning() {
+        let events = [
+            event(0, "InningStart", "", "", "Start of the top of the 1st."),
+            event(1, "NowBatting", "Yumi Pratt", "", "Now batting: Yumi Pratt"),
+            event(2, "Pitch", "Yumi Pratt", "93.2 MPH Fastball", "Ball 1."),
+            event(
+                3,
+                "Pitch",
+                "Yumi Pratt",
+                "88.0 MPH Slider",
+                "Yumi Pratt singles.",
+            ),
+            event(
+                4,
+                "NowBatting",
+                "Kelly Vieira",
+                "",
+                "Now batting: Kelly Vieira",
+            ),
+            event(
+                5,
+                "Pitch",
+                "Kelly Vieira",
+                "91.4 MPH Sinker",
+                "Kelly Vieira grounds out.",
+            ),
+        ];
+
+        let at_bats = group_into_at_bats(&events);
+        assert_eq!(at_bats.len(), 2);
+
+        let inning = Inning::DuringGame {
+            number: 1,
+            batting_side: TopBottom::Top,
+        };
+        assert_eq!(at_bats[0].batter, "Yumi Pratt");
+        assert_eq!(at_bats[0].inning, inning);
+        assert_eq!(at_bats[0].events.len(), 3);
+        assert_eq!(at_bats[0].pitches().count(), 2);
+        assert_eq!(*at_bats[0].end(), Ok(EventType::Pitch));
+
+        assert_eq!(at_bats[1].batter, "Kelly Vieira");
+        assert_eq!(at_bats[1].pitches().count(), 1);
+        assert_eq!(
+            at_bats[1].events.last().map(|event| event.message.as_str()),
+            Some("Kelly Vieira grounds out.")
+        );
+    }
+}
@@ -1,11 +1,17 @@
+use std::{cmp::Ordering, fmt::Display};
+
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
 use crate::utils::MaybeRecognizedHelper;
 use crate::{
-    enums::{EventType, Inning},
-    game::{EventBatterVersions, EventPitcherVersions, Pitch},
-    utils::{extra_fields_deserialize, MaybeRecognizedResult, NonStringOrEmptyString},
+    enums::{EventType, HomeAway, Inning, TopBottom},
+    game::{EventBatterVersions, EventPitcherVersions, MaybePlayer, Pitch},
+    utils::{
+        extra_fields_deserialize, MaybeRecognizedResult, NonStringOrEmptyString,
+        NumberOrEmptyString,
+    },
+    NotRecognized,
 };
 
 #[serde_as]
@@ -17,8 +23,8 @@ pub(crate) struct RawEvent {
     /// 2 when the game is over
     pub inning_side: u8,
 
-    pub away_score: u8,
-    pub home_score: u8,
+    pub away_score: u16,
+    pub home_score: u16,
 
     pub balls: Option<u8>,
     pub strikes: Option<u8>,
@@ -28,11 +34,13 @@ pub(crate) struct RawEvent {
     pub on_2b: bool,
     pub on_3b: bool,
 
-    /// Empty string between innings, null before game
+    /// Empty string between innings, null before game. Either a bare name or an object with the
+    /// player's id and stats, depending on when the game was played. Serializes back out in
+    /// whichever shape it was read as.
     pub on_deck: EventBatterVersions<String>,
-    /// Empty string between innings, null before game
+    /// Same as `on_deck`
     pub batter: EventBatterVersions<String>,
-    /// Empty string between innings, null before game
+    /// Same as `on_deck`
     pub pitcher: EventPitcherVersions<String>,
 
     /// Empty if none. Starting in s8 this can be missing sometimes
@@ -50,8 +58,11 @@ pub(crate) struct RawEvent {
     pub event: MaybeRecognizedResult<EventType>,
     pub message: String,
 
-    #[serde_as(as = "NonStringOrEmptyString")]
-    pub index: Option<u16>,
+    /// Missing entirely in older games and an empty string for events without one. Whichever of
+    /// those the source used is what gets serialized back out.
+    #[serde_as(as = "Option<NumberOrEmptyString>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index: Option<Option<u16>>,
 
     #[serde(flatten, deserialize_with = "extra_fields_deserialize")]
     pub extra_fields: serde_json::Map<String, serde_json::Value>,
@@ -62,9 +73,12 @@ pub(crate) struct RawEvent {
 #[serde(from = "RawEvent", into = "RawEvent")]
 pub struct Event {
     pub inning: Inning,
+    /// Some games were recorded as over in inning 0, which reads the same as being over after
+    /// the 1st. This remembers which one it was.
+    after_game_in_inning_zero: bool,
 
-    pub away_score: u8,
-    pub home_score: u8,
+    pub away_score: u16,
+    pub home_score: u16,
 
     pub balls: Option<u8>,
     pub strikes: Option<u8>,
@@ -80,6 +94,10 @@ pub struct Event {
 
     pub pitch: Option<Pitch>,
     pitch_info_is_some: bool,
+    /// Kept, along with its zone, so that pitch info still round trips when we can't parse it or
+    /// wouldn't write it back out the same way. Also holds a zone that came without pitch info.
+    unparsed_pitch_info: Option<(String, Option<u8>)>,
+    zone_is_some: bool,
     pub home_run_distance: Option<u32>,
 
     #[serde_as(as = "MaybeRecognizedHelper<_>")]
@@ -87,6 +105,7 @@ pub struct Event {
     pub message: String,
 
     pub index: Option<u16>,
+    index_is_some: bool,
 
     #[serde(flatten, deserialize_with = "extra_fields_deserialize")]
     pub extra_fields: serde_json::Map<String, serde_json::Value>,
@@ -101,9 +120,15 @@ impl From<RawEvent> for Event {
             (number, 2) => Inning::AfterGame {
                 final_inning_number: number - 1,
             },
-            (number, side) => Inning::DuringGame {
-                number,
-                batting_side: side.try_into().unwrap(),
+            (number, side) => match TopBottom::try_from(side) {
+                Ok(batting_side) => Inning::DuringGame {
+                    number,
+                    batting_side,
+                },
+                Err(e) => {
+                    tracing::warn!("Unexpected inning_side in inning {number}: {e}");
+                    Inning::Unknown { number, side }
+                }
             },
         };
 
@@ -114,15 +139,28 @@ impl From<RawEvent> for Event {
             None => None,
         };
 
-        // TODO Same as above
-        let pitch = pitch_info
-            .zip(value.zone)
-            .and_then(|(pitch_info, zone)| zone.map(|zone| Pitch::new(pitch_info, zone)));
+        let zone_is_some = value.zone.is_some();
+        let zone = value.zone.flatten();
+        let (pitch, unparsed_pitch_info) = match pitch_info {
+            Some(pitch_info) => match Pitch::new(&pitch_info, zone) {
+                Some(pitch) if pitch.clone().unparse().0 == pitch_info => (Some(pitch), None),
+                // e.g. "93 MPH", which would come back out as "93.0 MPH"
+                Some(pitch) => (Some(pitch), Some((pitch_info, zone))),
+                None => {
+                    tracing::warn!("Couldn't parse pitch info {pitch_info:?}");
+                    (None, Some((pitch_info, zone)))
+                }
+            },
+            None => (None, zone.map(|zone| (String::new(), Some(zone)))),
+        };
 
         Self {
             inning,
+            after_game_in_inning_zero: (value.inning, value.inning_side) == (0, 2),
             pitch,
             pitch_info_is_some,
+            unparsed_pitch_info,
+            zone_is_some,
             batter: value.batter,
             pitcher: value.pitcher,
             on_deck: value.on_deck,
@@ -137,11 +175,213 @@ impl From<RawEvent> for Event {
             on_3b: value.on_3b,
             message: value.message,
             extra_fields: value.extra_fields,
-            index: value.index,
+            index: value.index.flatten(),
+            index_is_some: value.index.is_some(),
             home_run_distance: value.home_run_distance,
         }
     }
 }
+/// Balls and strikes in the current at-bat
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Count {
+    pub balls: u8,
+    pub strikes: u8,
+}
+
+impl Count {
+    pub fn is_full(&self) -> bool {
+        self.balls == 3 && self.strikes == 2
+    }
+
+    pub fn is_two_strike(&self) -> bool {
+        self.strikes == 2
+    }
+}
+
+impl Display for Count {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.balls, self.strikes)
+    }
+}
+
+impl Event {
+    /// None if either balls or strikes is missing
+    pub fn count(&self) -> Option<Count> {
+        Some(Count {
+            balls: self.balls?,
+            strikes: self.strikes?,
+        })
+    }
+
+    /// Home score minus away score, so positive means the home team is ahead.
+    pub fn score_differential(&self) -> i32 {
+        i32::from(self.home_score) - i32::from(self.away_score)
+    }
+
+    /// Whether a pitch the batter took was called a strike, going by the message: "Strike,
+    /// looking" or a strikeout looking is a called strike and "Ball" is not. None when there was
+    /// no call to make, i.e. for anything that isn't a pitch and for pitches the batter swung at,
+    /// was hit by or put in play.
+    pub fn pitch_called_strike(&self) -> Option<bool> {
+        if self.event != Ok(EventType::Pitch) || self.pitch.is_none() {
+            return None;
+        }
+
+        if self.message.starts_with("Strike, looking")
+            || self.message.contains(" strikes out looking")
+            || self.message.contains(" struck out looking")
+        {
+            Some(true)
+        } else if self.message.starts_with("Ball. ") || self.message.starts_with("Ball 4. ") {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// The opposite of [`Self::pitch_called_strike`], with the same None cases
+    pub fn pitch_called_ball(&self) -> Option<bool> {
+        self.pitch_called_strike().map(|strike| !strike)
+    }
+
+    /// Whether the call on a taken pitch agreed with where it crossed the plate. None when there
+    /// was no call (see [`Self::pitch_called_strike`]) or the zone is unknown.
+    pub fn pitch_call_matches_zone(&self) -> Option<bool> {
+        let in_zone = self.pitch.as_ref()?.in_strike_zone()?;
+        Some(self.pitch_called_strike()? == in_zone)
+    }
+
+    /// Which team is ahead. None when the score is tied.
+    pub fn leader(&self) -> Option<HomeAway> {
+        match self.home_score.cmp(&self.away_score) {
+            Ordering::Greater => Some(HomeAway::Home),
+            Ordering::Less => Some(HomeAway::Away),
+            Ordering::Equal => None,
+        }
+    }
+}
+
+/// What changed from one event to the next. Each field is None when it stayed the same and holds
+/// the new value when it changed. Fields that are themselves optional, like the count, go from
+/// `Some(None)` to `Some(Some(..))` when they first show up at the start of the game.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EventDiff<'a> {
+    pub inning: Option<Inning>,
+    /// (away, home)
+    pub score: Option<(u16, u16)>,
+    /// First, second, third
+    pub bases: Option<[bool; 3]>,
+    pub count: Option<Option<Count>>,
+    pub outs: Option<Option<u8>>,
+    pub batter: Option<MaybePlayer<&'a str>>,
+    pub pitcher: Option<MaybePlayer<&'a str>>,
+}
+
+impl EventDiff<'_> {
+    /// Nothing changed
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Event {
+    /// What changed between this event and `next`. Batters and pitchers are compared by name, so
+    /// stats changing on the object form doesn't count.
+    pub fn diff<'a>(&self, next: &'a Event) -> EventDiff<'a> {
+        fn changed<T: PartialEq>(before: T, after: T) -> Option<T> {
+            (before != after).then_some(after)
+        }
+
+        EventDiff {
+            inning: changed(self.inning, next.inning),
+            score: changed(
+                (self.away_score, self.home_score),
+                (next.away_score, next.home_score),
+            ),
+            bases: changed(
+                [self.on_1b, self.on_2b, self.on_3b],
+                [next.on_1b, next.on_2b, next.on_3b],
+            ),
+            count: changed(self.count(), next.count()),
+            outs: changed(self.outs, next.outs),
+            batter: (self.batter.maybe_name() != next.batter.maybe_name())
+                .then(|| next.batter.maybe_name()),
+            pitcher: (self.pitcher.maybe_name() != next.pitcher.maybe_name())
+                .then(|| next.pitcher.maybe_name()),
+        }
+    }
+}
+
+/// An [`Event`] flattened into scalar columns, for writing out with `csv` or similar
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EventRow {
+    /// 0 before the game, the final inning after it
+    pub inning: u8,
+    /// None outside of an inning
+    pub inning_side: Option<TopBottom>,
+    pub away_score: u16,
+    pub home_score: u16,
+    pub balls: Option<u8>,
+    pub strikes: Option<u8>,
+    pub outs: Option<u8>,
+    pub on_1b: bool,
+    pub on_2b: bool,
+    pub on_3b: bool,
+    pub pitch_speed: Option<f32>,
+    pub pitch_type: Option<String>,
+    pub pitch_zone: Option<u8>,
+    pub event_type: String,
+    pub message: String,
+    pub index: Option<u16>,
+}
+
+impl Event {
+    pub fn to_flat_row(&self) -> EventRow {
+        // Unrecognized values are kept as their raw JSON, which for strings means quotes
+        fn recognized_or_raw<T: Display>(value: &MaybeRecognizedResult<T>) -> String {
+            match value {
+                Ok(value) => value.to_string(),
+                Err(NotRecognized(serde_json::Value::String(raw))) => raw.clone(),
+                Err(NotRecognized(raw)) => raw.to_string(),
+            }
+        }
+
+        let (inning, inning_side) = match self.inning {
+            Inning::BeforeGame => (0, None),
+            Inning::DuringGame {
+                number,
+                batting_side,
+            } => (number, Some(batting_side)),
+            Inning::AfterGame {
+                final_inning_number,
+            } => (final_inning_number, None),
+            Inning::Unknown { number, .. } => (number, None),
+        };
+
+        EventRow {
+            inning,
+            inning_side,
+            away_score: self.away_score,
+            home_score: self.home_score,
+            balls: self.balls,
+            strikes: self.strikes,
+            outs: self.outs,
+            on_1b: self.on_1b,
+            on_2b: self.on_2b,
+            on_3b: self.on_3b,
+            pitch_speed: self.pitch.as_ref().map(|pitch| pitch.speed),
+            pitch_type: self
+                .pitch
+                .as_ref()
+                .map(|pitch| recognized_or_raw(&pitch.pitch_type)),
+            pitch_zone: self.pitch.as_ref().and_then(|pitch| pitch.zone),
+            event_type: recognized_or_raw(&self.event),
+            message: self.message.clone(),
+            index: self.index,
+        }
+    }
+}
+
 impl From<Event> for RawEvent {
     fn from(value: Event) -> Self {
         let (inning, inning_side) = match value.inning {
@@ -152,19 +392,26 @@ impl From<Event> for RawEvent {
             } => (number, side.into()),
             Inning::AfterGame {
                 final_inning_number: 1,
-            } => (0, 2),
+            } if value.after_game_in_inning_zero => (0, 2),
             Inning::AfterGame {
                 final_inning_number,
             } => (final_inning_number + 1, 2),
+            Inning::Unknown { number, side } => (number, side),
+        };
+        // The stored string only wins while `pitch` is still what it was parsed into, so that
+        // edits to `pitch` aren't silently thrown away
+        let (pitch_info, zone) = match value.unparsed_pitch_info {
+            Some((pitch_info, zone)) if Pitch::new(&pitch_info, zone) == value.pitch => {
+                (pitch_info, zone)
+            }
+            _ => value
+                .pitch
+                .map(Pitch::unparse)
+                .unwrap_or(("".to_string(), None)),
         };
-        let (pitch_info, zone) = value
-            .pitch
-            .map(Pitch::unparse)
-            .map(|(pitch, zone)| (pitch, Some(zone)))
-            .unwrap_or(("".to_string(), None));
 
         let pitch_info = value.pitch_info_is_some.then_some(pitch_info);
-        let zone = value.pitch_info_is_some.then_some(zone);
+        let zone = value.zone_is_some.then_some(zone);
 
         Self {
             inning,
@@ -185,8 +432,412 @@ impl From<Event> for RawEvent {
             on_3b: value.on_3b,
             message: value.message,
             extra_fields: value.extra_fields,
-            index: value.index,
+            index: (value.index_is_some || value.index.is_some()).then_some(value.index),
             home_run_distance: value.home_run_distance,
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+
+    use crate::{
+        enums::{HomeAway, Inning},
+        game::{Count, Event, EventDiff, MaybePlayer, Pitch},
+        utils::game_event,
+    };
+
+    use super::RawEvent;
+
+    fn raw_event(pitch_info: &str, zone: serde_json::Value) -> RawEvent {
+        game_event(serde_json::json!({ "pitch_info": pitch_info, "zone": zone }))
+    }
+
+    #[test]
+    fn pitch_without_zone() {
+        for zone in [serde_json::Value::Null, "".into()] {
+            let raw = raw_event("93.2 MPH Fastball", zone);
+            let event = Event::from(raw.clone());
+
+            let pitch = event.pitch.as_ref().expect("pitch should be kept");
+            assert_eq!(pitch.zone, None);
+            assert_eq!(
+                pitch.clone().unparse(),
+                ("93.2 MPH Fastball".to_string(), None)
+            );
+
+            assert_eq!(RawEvent::from(event), raw);
+        }
+    }
+
+    #[test]
+    fn pitch_with_zone() {
+        let raw = raw_event("93.2 MPH Fastball", 14.into());
+        let event = Event::from(raw.clone());
+
+        assert_eq!(event.pitch.as_ref().and_then(|pitch| pitch.zone), Some(14));
+        assert_eq!(RawEvent::from(event), raw);
+    }
+
+    #[test]
+    fn unknown_inning_side() {
+        let mut raw = raw_event("", "".into());
+        raw.inning_side = 5;
+
+        let event = Event::from(raw.clone());
+        assert_eq!(event.inning, Inning::Unknown { number: 1, side: 5 });
+        assert_eq!(RawEvent::from(event), raw);
+    }
+
+    #[test]
+    fn pregame_and_postgame_innings() {
+        let mut raw = raw_event("", "".into());
+
+        raw.inning = 0;
+        raw.inning_side = 1;
+        let event = Event::from(raw.clone());
+        assert_eq!(event.inning, Inning::BeforeGame);
+        assert_eq!(RawEvent::from(event), raw);
+
+        // Game over after the 1st, written both ways it shows up
+        for inning in [0, 2] {
+            raw.inning = inning;
+            raw.inning_side = 2;
+            let event = Event::from(raw.clone());
+            assert_eq!(
+                event.inning,
+                Inning::AfterGame {
+                    final_inning_number: 1
+                }
+            );
+            assert_eq!(RawEvent::from(event), raw);
+        }
+    }
+
+    #[test]
+    fn raw_event_round_trips() {
+        let innings = [
+            (0, 0),
+            (0, 1),
+            (0, 2),
+            (1, 2),
+            (2, 2),
+            (9, 2),
+            (3, 0),
+            (3, 1),
+            (4, 7),
+        ];
+        let pitch_infos = [
+            None,
+            Some(""),
+            Some("93.2 MPH Fastball"),
+            Some("93 MPH Fastball"),
+            Some("90.1 MPH Changeup Sneaky"),
+            Some("Very Fast"),
+        ];
+        let zones = [None, Some(None), Some(Some(4))];
+        let indices = [None, Some(None), Some(Some(3))];
+
+        for (((inning, pitch_info), zone), index) in innings
+            .into_iter()
+            .cartesian_product(pitch_infos)
+            .cartesian_product(zones)
+            .cartesian_product(indices)
+        {
+            let mut raw = raw_event("", "".into());
+            (raw.inning, raw.inning_side) = inning;
+            raw.pitch_info = pitch_info.map(str::to_string);
+            raw.zone = zone;
+            raw.index = index;
+
+            assert_eq!(RawEvent::from(Event::from(raw.clone())), raw);
+        }
+    }
+
+    #[cfg(feature = "unrecognized-hook")]
+    #[test]
+    fn unrecognized_hook_sees_event_type() {
+        use std::{cell::RefCell, rc::Rc};
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let collector = seen.clone();
+        crate::set_unrecognized_hook(move |value, type_name| {
+            collector.borrow_mut().push((value.clone(), type_name))
+        });
+
+        let mut raw = serde_json::to_value(raw_event("", serde_json::Value::Null)).unwrap();
+        raw["event"] = "SomethingNew".into();
+        let raw: RawEvent = serde_json::from_value(raw).unwrap();
+        crate::clear_unrecognized_hook();
+
+        assert!(raw.event.is_err());
+        let seen = seen.borrow();
+        assert_eq!(seen.len(), 1);
+        assert_eq!(seen[0].0, serde_json::Value::from("SomethingNew"));
+        assert!(seen[0].1.ends_with("EventType"));
+    }
+
+    #[test]
+    fn score_leader() {
+        let mut raw = raw_event("", serde_json::Value::Null);
+        for (away_score, home_score, differential, leader) in [
+            (0, 0, 0, None),
+            (0, 2, 2, Some(HomeAway::Home)),
+            (3, 2, -1, Some(HomeAway::Away)),
+            (3, 3, 0, None),
+            (12, 0, -12, Some(HomeAway::Away)),
+            (300, 2, -298, Some(HomeAway::Away)),
+        ] {
+            raw.away_score = away_score;
+            raw.home_score = home_score;
+            let event = Event::from(raw.clone());
+
+            assert_eq!(event.score_differential(), differential);
+            assert_eq!(event.leader(), leader);
+        }
+    }
+
+    #[test]
+    fn count() {
+        let mut raw = raw_event("", serde_json::Value::Null);
+
+        raw.balls = Some(3);
+        raw.strikes = Some(2);
+        let count = Event::from(raw.clone()).count().unwrap();
+        assert!(count.is_full());
+        assert!(count.is_two_strike());
+        assert_eq!(count.to_string(), "3-2");
+
+        raw.balls = Some(0);
+        raw.strikes = Some(0);
+        let count = Event::from(raw.clone()).count().unwrap();
+        assert!(!count.is_full());
+        assert!(!count.is_two_strike());
+        assert_eq!(count.to_string(), "0-0");
+
+        raw.strikes = None;
+        assert_eq!(Event::from(raw).count(), None);
+    }
+
+    #[test]
+    fn unparseable_pitch_info() {
+        let raw = raw_event("Very Fast", serde_json::Value::from(4));
+        let event = Event::from(raw.clone());
+
+        assert_eq!(event.pitch, None);
+        assert_eq!(RawEvent::from(event), raw);
+    }
+
+    #[test]
+    fn edited_pitch_replaces_pitch_info() {
+        // "93 MPH" would be written back out as "93.0 MPH", so the original is kept...
+        let raw = raw_event("93 MPH Fastball", 4.into());
+        let mut event = Event::from(raw.clone());
+        assert_eq!(RawEvent::from(event.clone()), raw);
+
+        // ...but only until the pitch is changed
+        event.pitch.as_mut().unwrap().speed = 95.0;
+        let edited = RawEvent::from(event);
+        assert_eq!(edited.pitch_info.as_deref(), Some("95.0 MPH Fastball"));
+        assert_eq!(edited.zone, Some(Some(4)));
+
+        let mut event = Event::from(raw_event("Very Fast", 4.into()));
+        event.pitch = Pitch::new("93.2 MPH Fastball", Some(4));
+        let edited = RawEvent::from(event);
+        assert_eq!(edited.pitch_info.as_deref(), Some("93.2 MPH Fastball"));
+    }
+
+    #[test]
+    fn index_shapes() {
+        let mut value = serde_json::to_value(raw_event("", "".into())).unwrap();
+        let cases = [
+            (None, None),
+            (Some(serde_json::json!("")), None),
+            (Some(serde_json::json!(12)), Some(12)),
+            (Some(serde_json::json!("12")), Some(12)),
+        ];
+        for (index, expected) in cases {
+            match &index {
+                Some(index) => value["index"] = index.clone(),
+                None => {
+                    value.as_object_mut().unwrap().remove("index");
+                }
+            }
+            let event: Event = serde_json::from_value(value.clone()).unwrap();
+            assert_eq!(event.index, expected);
+
+            let reserialized = serde_json::to_value(&event).unwrap();
+            // Numeric strings come back out as numbers, everything else as it went in
+            let expected_index = match expected {
+                Some(index) => Some(serde_json::json!(index)),
+                None => index,
+            };
+            assert_eq!(reserialized.get("index"), expected_index.as_ref());
+        }
+
+        value["index"] = "twelve".into();
+        assert!(serde_json::from_value::<Event>(value).is_err());
+    }
+
+    #[test]
+    fn flat_row() {
+        let event = Event::from(raw_event("93.2 MPH Fastball", 7.into()));
+        let row = event.to_flat_row();
+        assert_eq!(row.inning, 1);
+        assert_eq!(row.inning_side, Some(crate::enums::TopBottom::Top));
+        assert_eq!(
+            (row.balls, row.strikes, row.outs),
+            (Some(0), Some(0), Some(0))
+        );
+        assert!(!row.on_1b && !row.on_2b && !row.on_3b);
+        assert_eq!(row.pitch_speed, Some(93.2));
+        assert_eq!(row.pitch_type.as_deref(), Some("Fastball"));
+        assert_eq!(row.pitch_zone, Some(7));
+        assert_eq!(row.event_type, "Pitch");
+        assert_eq!(row.message, "Yumi Pratt walks intentionally.");
+        assert_eq!(row.index, Some(6));
+
+        // Every column is a scalar, so it can go straight into a csv
+        let serialized = serde_json::to_value(&row).unwrap();
+        let columns = serialized.as_object().unwrap();
+        assert_eq!(columns.len(), 16);
+        assert!(columns
+            .values()
+            .all(|value| !value.is_object() && !value.is_array()));
+        assert_eq!(columns["inning_side"], "Top");
+    }
+
+    #[test]
+    fn batter_string_or_object() {
+        let object = serde_json::json!({
+            "id": "687c026d102dff82d4f6f1cd",
+            "pa": "0 for 0",
+            "avg": 0,
+            "bats": "R",
+            "name": "Yumi Pratt",
+        });
+        for batter in [serde_json::json!("Yumi Pratt"), object] {
+            let mut value = serde_json::to_value(raw_event("", "".into())).unwrap();
+            value["batter"] = batter.clone();
+            let raw: RawEvent = serde_json::from_value(value).unwrap();
+            assert_eq!(raw.batter.current_name(), Some("Yumi Pratt"));
+            assert_eq!(raw.batter.id().is_some(), batter.is_object());
+
+            let event = Event::from(raw);
+            assert_eq!(serde_json::to_value(&event).unwrap()["batter"], batter);
+        }
+    }
+
+    #[test]
+    fn diff() {
+        let mut raw = raw_event("", serde_json::Value::Null);
+        raw.on_1b = true;
+        raw.balls = Some(1);
+        raw.strikes = Some(1);
+        let before = Event::from(raw.clone());
+
+        // Ground into a double play, clearing first
+        raw.on_1b = false;
+        raw.outs = Some(2);
+        raw.balls = Some(0);
+        raw.strikes = Some(0);
+        raw.message = "Yumi Pratt grounds into a double play.".to_string();
+        let after = Event::from(raw.clone());
+
+        let diff = before.diff(&after);
+        assert_eq!(
+            diff,
+            EventDiff {
+                bases: Some([false, false, false]),
+                count: Some(Some(Count {
+                    balls: 0,
+                    strikes: 0
+                })),
+                outs: Some(Some(2)),
+                ..Default::default()
+            }
+        );
+        assert!(after.diff(&after).is_empty());
+
+        // Pregame to the first pitch
+        let mut pregame = raw.clone();
+        pregame.inning = 0;
+        pregame.inning_side = 1;
+        pregame.balls = None;
+        pregame.strikes = None;
+        pregame.outs = None;
+        pregame.batter = serde_json::from_value(serde_json::Value::Null).unwrap();
+        pregame.pitcher = serde_json::from_value(serde_json::Value::Null).unwrap();
+        let diff = Event::from(pregame).diff(&after);
+        assert_eq!(diff.inning, Some(after.inning));
+        assert_eq!(diff.count, Some(after.count()));
+        assert_eq!(diff.outs, Some(Some(2)));
+        assert_eq!(diff.batter, Some(MaybePlayer::Player("Yumi Pratt")));
+        assert_eq!(diff.pitcher, Some(MaybePlayer::Player("Ye-Jin Jallow")));
+        assert_eq!(diff.score, None);
+    }
+
+    /// The field names are the wire keys, so a captured event should have nothing left over. A
+    /// key that changes upstream would show up here instead of silently landing in extra_fields.
+    #[test]
+    fn captured_events_have_no_extra_fields() -> Result<(), Box<dyn std::error::Error>> {
+        let mut paths = vec![
+            std::path::PathBuf::from("test_data/s2_d240_game.json"),
+            std::path::PathBuf::from("test_data/livingston_game.json"),
+        ];
+        for entry in std::path::Path::new("test_data/raw/game").read_dir()? {
+            paths.push(entry?.path());
+        }
+
+        for path in paths {
+            let game: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path)?)?;
+            for event in game["EventLog"]
+                .as_array()
+                .expect("games have an event log")
+            {
+                let raw: RawEvent = serde_json::from_value(event.clone())?;
+                assert!(
+                    raw.extra_fields.is_empty(),
+                    "{}: {:?}",
+                    path.display(),
+                    raw.extra_fields
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn pitch_calls() {
+        let pitch = |zone: u8, message: &str| {
+            let mut raw = raw_event("93.2 MPH Fastball", zone.into());
+            raw.message = message.to_string();
+            Event::from(raw)
+        };
+
+        let called_strike = pitch(5, "Strike, looking. 0-1.");
+        assert_eq!(called_strike.pitch_called_strike(), Some(true));
+        assert_eq!(called_strike.pitch_called_ball(), Some(false));
+        assert_eq!(called_strike.pitch_call_matches_zone(), Some(true));
+
+        let ball = pitch(12, "Ball. 1-0.");
+        assert_eq!(ball.pitch_called_strike(), Some(false));
+        assert_eq!(ball.pitch_called_ball(), Some(true));
+        assert_eq!(ball.pitch_call_matches_zone(), Some(true));
+
+        let strike_looking = pitch(11, "Yumi Pratt strikes out looking.");
+        assert_eq!(strike_looking.pitch_called_strike(), Some(true));
+        assert_eq!(strike_looking.pitch_call_matches_zone(), Some(false));
+
+        // The batter swung, so nothing was called
+        let swinging = pitch(5, "Strike, swinging. 0-1.");
+        assert_eq!(swinging.pitch_called_strike(), None);
+        assert_eq!(swinging.pitch_call_matches_zone(), None);
+
+        // No pitch at all
+        let mut raw = raw_event("", serde_json::Value::Null);
+        raw.message = "Ball. 1-0.".to_string();
+        assert_eq!(Event::from(raw).pitch_called_strike(), None);
+    }
+}
@@ -2,8 +2,9 @@ use std::collections::HashMap;
 
 use crate::utils::{ExpectNone, MaybeRecognizedHelper, SometimesMissingHelper};
 use crate::{
-    enums::{Day, GameStat, LeagueScale, SeasonStatus, Slot},
+    enums::{Day, GameStat, HomeAway, LeagueScale, SeasonStatus, Slot},
     game::{Event, PitcherEntry, Weather},
+    parsed_event::EmojiTeam,
     utils::{extra_fields_deserialize, AddedLaterResult, MaybeRecognizedResult},
 };
 
@@ -161,6 +162,32 @@ pub struct Game {
     pub extra_fields: serde_json::Map<String, serde_json::Value>,
 }
 
+impl Game {
+    pub fn team(&self, side: HomeAway) -> EmojiTeam<&str> {
+        match side {
+            HomeAway::Away => EmojiTeam {
+                emoji: &self.away_team_emoji,
+                name: &self.away_team_name,
+            },
+            HomeAway::Home => EmojiTeam {
+                emoji: &self.home_team_emoji,
+                name: &self.home_team_name,
+            },
+        }
+    }
+
+    /// The team at bat during `event`. The away team bats in the top of the inning. None before
+    /// and after the game.
+    pub fn batting_team(&self, event: &Event) -> Option<EmojiTeam<&str>> {
+        event.inning.batting_team().map(|side| self.team(side))
+    }
+
+    /// The team in the field during `event`. None before and after the game.
+    pub fn pitching_team(&self, event: &Event) -> Option<EmojiTeam<&str>> {
+        event.inning.pitching_team().map(|side| self.team(side))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuroraPhoto {
     pub luck: f64,
@@ -7,6 +7,7 @@ use serde::de::value::{
 use serde::de::Visitor;
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_with::serde_as;
+use strum::IntoEnumIterator;
 
 use crate::enums::{Handedness, PitchType};
 use crate::utils::{
@@ -14,12 +15,16 @@ use crate::utils::{
     MaybeRecognizedHelper, MaybeRecognizedResult, ZeroOrF64,
 };
 
+pub(crate) mod at_bat;
 pub(crate) mod event;
 pub(crate) mod game;
+pub(crate) mod timeline;
 pub(crate) mod weather;
 
-pub use event::Event;
+pub use at_bat::{group_into_at_bats, AtBat};
+pub use event::{Count, Event, EventDiff, EventRow};
 pub use game::Game;
+pub use timeline::{merge_timeline, TimelineEntry};
 pub use weather::Weather;
 
 #[serde_as]
@@ -64,6 +69,35 @@ impl<S> EventPitcherVersions<S> {
             EventPitcherVersions::New(p) => p.name,
         }
     }
+
+    /// Like `name`, but borrows instead of consuming
+    pub fn name_ref(&self) -> &MaybePlayer<S> {
+        match self {
+            EventPitcherVersions::Old(p) => p,
+            EventPitcherVersions::New(p) => &p.name,
+        }
+    }
+}
+
+impl<S: AsRef<str>> EventPitcherVersions<S> {
+    /// See [`EventBatterVersions::maybe_name`]
+    pub fn maybe_name(&self) -> MaybePlayer<&str> {
+        match self {
+            EventPitcherVersions::Old(p) => p.as_str(),
+            EventPitcherVersions::New(p) => p.name.as_str(),
+        }
+    }
+
+    pub fn current_name(&self) -> Option<&str> {
+        self.maybe_name().player()
+    }
+
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            EventPitcherVersions::Old(_) => None,
+            EventPitcherVersions::New(p) => Some(p.id.as_ref()),
+        }
+    }
 }
 
 impl<'de, S> Deserialize<'de> for EventPitcherVersions<S>
@@ -150,6 +184,38 @@ impl<S> EventBatterVersions<S> {
             EventBatterVersions::New(p) => p.name,
         }
     }
+
+    /// Like `name`, but borrows instead of consuming
+    pub fn name_ref(&self) -> &MaybePlayer<S> {
+        match self {
+            EventBatterVersions::Old(p) => p,
+            EventBatterVersions::New(p) => &p.name,
+        }
+    }
+}
+
+impl<S: AsRef<str>> EventBatterVersions<S> {
+    /// The name without consuming self. Both versions can be empty between innings or null
+    /// before the game.
+    pub fn maybe_name(&self) -> MaybePlayer<&str> {
+        match self {
+            EventBatterVersions::Old(p) => p.as_str(),
+            EventBatterVersions::New(p) => p.name.as_str(),
+        }
+    }
+
+    /// None between innings and before the game
+    pub fn current_name(&self) -> Option<&str> {
+        self.maybe_name().player()
+    }
+
+    /// Only the newer object version has an id
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            EventBatterVersions::Old(_) => None,
+            EventBatterVersions::New(p) => Some(p.id.as_ref()),
+        }
+    }
 }
 
 impl<'de, S> Deserialize<'de> for EventBatterVersions<S>
@@ -170,7 +236,7 @@ where
         {
             type Value = EventBatterVersions<S>;
             fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                write!(formatter, "an event pitcher")
+                write!(formatter, "a batter's name or an event batter")
             }
 
             fn visit_unit<E>(self) -> Result<Self::Value, E>
@@ -317,6 +383,15 @@ impl<S> MaybePlayer<S> {
         }
     }
 }
+impl<S: AsRef<str>> MaybePlayer<S> {
+    pub fn as_str(&self) -> MaybePlayer<&str> {
+        match self {
+            MaybePlayer::Player(player) => MaybePlayer::Player(player.as_ref()),
+            MaybePlayer::EmptyString => MaybePlayer::EmptyString,
+            MaybePlayer::Null => MaybePlayer::Null,
+        }
+    }
+}
 impl MaybePlayer<String> {
     pub fn map_as_str(&self) -> MaybePlayer<&str> {
         match self {
@@ -357,26 +432,57 @@ pub struct Pitch {
     pub speed: f32,
     #[serde_as(as = "MaybeRecognizedHelper<_>")]
     pub pitch_type: MaybeRecognizedResult<PitchType>,
-    pub zone: u8,
+    /// Missing for some pitches that never reach the plate, e.g. intentional walks
+    pub zone: Option<u8>,
+    /// Anything in `pitch_info` after the pitch type. Only split off when the pitch type is one
+    /// we recognize, otherwise there's no telling where the type ends.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub descriptor: Option<String>,
 }
 impl Pitch {
-    pub fn new(pitch_info: String, zone: u8) -> Self {
-        let mut iter = pitch_info.split(" MPH ");
-        let pitch_speed = iter.next().unwrap().parse().unwrap();
-        let pitch_type = maybe_recognized_from_str(iter.next().unwrap());
-        Self {
-            speed: pitch_speed,
+    /// None if `pitch_info` isn't "{speed} MPH {pitch type}", optionally followed by more words
+    pub fn new(pitch_info: &str, zone: Option<u8>) -> Option<Self> {
+        let (speed, rest) = pitch_info.split_once(" MPH ")?;
+        let (pitch_type, descriptor) = PitchType::iter()
+            .find_map(|pitch_type| {
+                let after = rest.strip_prefix(pitch_type.to_string().as_str())?;
+                if after.is_empty() {
+                    Some((Ok(pitch_type), None))
+                } else {
+                    let descriptor = after.strip_prefix(' ')?;
+                    Some((Ok(pitch_type), Some(descriptor.to_string())))
+                }
+            })
+            .unwrap_or_else(|| (maybe_recognized_from_str(rest), None));
+
+        Some(Self {
+            speed: speed.parse().ok()?,
             pitch_type,
             zone,
+            descriptor,
+        })
+    }
+    /// Zones 1-9 are the strike zone and 11-14 are the four corners outside it. None when there's
+    /// no zone or it's some other number.
+    pub fn in_strike_zone(&self) -> Option<bool> {
+        match self.zone? {
+            1..=9 => Some(true),
+            11..=14 => Some(false),
+            _ => None,
         }
     }
-    pub fn unparse(self) -> (String, u8) {
+
+    pub fn unparse(self) -> (String, Option<u8>) {
         let speed = format!("{:.1}", self.speed);
         // let speed = speed.strip_suffix(".0").unwrap_or(speed.as_str());
-        let pitch_info = format!(
+        let mut pitch_info = format!(
             "{speed} MPH {}",
             maybe_recognized_to_string(&self.pitch_type)
         );
+        if let Some(descriptor) = self.descriptor {
+            pitch_info.push(' ');
+            pitch_info.push_str(&descriptor);
+        }
         (pitch_info, self.zone)
     }
 }
@@ -408,10 +514,13 @@ mod test {
     use tracing_test::traced_test;
 
     use crate::{
+        enums::{Inning, PitchType, TopBottom},
         utils::{assert_round_trip, no_tracing_errs},
         Game,
     };
 
+    use super::Pitch;
+
     #[test]
     fn game_round_trip() -> Result<(), Box<dyn std::error::Error>> {
         let no_tracing_errs = no_tracing_errs();
@@ -440,4 +549,112 @@ mod test {
         });
         Ok(())
     }
+
+    #[test]
+    fn batter_versions() -> Result<(), Box<dyn std::error::Error>> {
+        use super::{EventBatterVersions, EventPitcherVersions, MaybePlayer};
+
+        let batter: EventBatterVersions<String> = serde_json::from_str("\"Yumi Pratt\"")?;
+        assert_eq!(batter.current_name(), Some("Yumi Pratt"));
+        assert_eq!(batter.id(), None);
+
+        let batter: EventBatterVersions<String> = serde_json::from_str("\"\"")?;
+        assert_eq!(batter.maybe_name(), MaybePlayer::EmptyString);
+        assert_eq!(batter.current_name(), None);
+
+        let batter: EventBatterVersions<String> = serde_json::from_str("null")?;
+        assert_eq!(batter.maybe_name(), MaybePlayer::Null);
+        assert_eq!(batter.current_name(), None);
+
+        let batter: EventBatterVersions<String> = serde_json::from_value(serde_json::json!({
+            "id": "68412742ed58166c1895ae31",
+            "pa": "1st PA",
+            "avg": 0,
+            "bats": "R",
+            "name": "Yumi Pratt",
+        }))?;
+        assert_eq!(batter.current_name(), Some("Yumi Pratt"));
+        assert_eq!(batter.id(), Some("68412742ed58166c1895ae31"));
+
+        let pitcher: EventPitcherVersions<String> = serde_json::from_value(serde_json::json!({
+            "id": "684346d2e63d9bb87288a285",
+            "era": 0,
+            "name": null,
+            "throws": "L",
+            "pitches": 0,
+        }))?;
+        assert_eq!(pitcher.maybe_name(), MaybePlayer::Null);
+        assert_eq!(pitcher.id(), Some("684346d2e63d9bb87288a285"));
+        Ok(())
+    }
+
+    #[test]
+    fn pitch_descriptor() {
+        let pitch = Pitch::new("95.3 MPH Fastball", Some(5)).unwrap();
+        assert_eq!(pitch.pitch_type, Ok(PitchType::Fastball));
+        assert_eq!(pitch.descriptor, None);
+        assert_eq!(pitch.unparse(), ("95.3 MPH Fastball".to_string(), Some(5)));
+
+        let pitch = Pitch::new("81.4 MPH Knuckle Curve Low and Away", Some(13)).unwrap();
+        assert_eq!(pitch.pitch_type, Ok(PitchType::KnuckleCurve));
+        assert_eq!(pitch.descriptor.as_deref(), Some("Low and Away"));
+        assert_eq!(
+            pitch.unparse(),
+            ("81.4 MPH Knuckle Curve Low and Away".to_string(), Some(13))
+        );
+
+        // Not a word boundary, so not a descriptor
+        let pitch = Pitch::new("95.3 MPH Fastballs", None).unwrap();
+        assert!(pitch.pitch_type.is_err());
+        assert_eq!(pitch.descriptor, None);
+        assert_eq!(pitch.unparse().0, "95.3 MPH Fastballs");
+    }
+
+    #[test]
+    fn batting_and_pitching_teams() -> Result<(), Box<dyn std::error::Error>> {
+        let game: Game =
+            serde_json::from_str(&std::fs::read_to_string("test_data/s2_d240_game.json")?)?;
+        let thieves = "🤖 Wonder Lake Divinity Thieves".to_string();
+        let alchemists = "⚗️ Frederick Late Night Alchemists".to_string();
+        let teams = |event| {
+            (
+                game.batting_team(event).map(|team| team.to_string()),
+                game.pitching_team(event).map(|team| team.to_string()),
+            )
+        };
+
+        // Pregame
+        assert_eq!(teams(&game.event_log[0]), (None, None));
+
+        let top = game
+            .event_log
+            .iter()
+            .find(|event| {
+                event.inning
+                    == Inning::DuringGame {
+                        number: 1,
+                        batting_side: TopBottom::Top,
+                    }
+            })
+            .expect("game has a top of the 1st");
+        assert_eq!(
+            teams(top),
+            (Some(thieves.clone()), Some(alchemists.clone()))
+        );
+
+        let bottom = game
+            .event_log
+            .iter()
+            .find(|event| {
+                event.inning
+                    == Inning::DuringGame {
+                        number: 1,
+                        batting_side: TopBottom::Bottom,
+                    }
+            })
+            .expect("game has a bottom of the 1st");
+        assert_eq!(teams(bottom), (Some(alchemists), Some(thieves)));
+
+        Ok(())
+    }
 }
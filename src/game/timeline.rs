@@ -0,0 +1,111 @@
+use crate::{
+    feed_event::FeedEvent,
+    game::Event,
+    team_feed::{parse_team_feed_event, ParsedTeamFeedEventText},
+};
+
+/// There are only ever a handful of feed events per game, so they aren't worth boxing
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimelineEntry<'a> {
+    Game(&'a Event),
+    Feed(ParsedTeamFeedEventText<&'a str>),
+}
+
+/// Puts a game's events and the feed events that go with it on one timeline.
+///
+/// Game events don't have timestamps, so they're placed using the feed instead: the game's own
+/// result (the `GameResult` whose score matches the last event's) is posted as soon as the game
+/// ends, so the game events go right before it, in the order they're given in. Feed events are
+/// parsed as team feed events and ordered by season and then timestamp. The day isn't used, so
+/// a feed event whose day is missing or unrecognized still lands where its timestamp puts it.
+/// If none of the feed events is this game's result, the game events go after all of them.
+pub fn merge_timeline<'a>(events: &'a [Event], feed: &'a [FeedEvent]) -> Vec<TimelineEntry<'a>> {
+    let mut feed = feed
+        .iter()
+        .map(|event| (event, parse_team_feed_event(event)))
+        .collect::<Vec<_>>();
+    feed.sort_by_key(|(event, _)| (event.season, event.timestamp));
+
+    let game_end = events
+        .last()
+        .and_then(|last| {
+            feed.iter().position(|(_, parsed)| {
+                matches!(
+                    parsed,
+                    ParsedTeamFeedEventText::GameResult { away_score, home_score, .. }
+                        if *away_score == last.away_score && *home_score == last.home_score
+                )
+            })
+        })
+        .unwrap_or(feed.len());
+    let after_game = feed.split_off(game_end);
+
+    feed.into_iter()
+        .map(|(_, parsed)| TimelineEntry::Feed(parsed))
+        .chain(events.iter().map(TimelineEntry::Game))
+        .chain(
+            after_game
+                .into_iter()
+                .map(|(_, parsed)| TimelineEntry::Feed(parsed)),
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Duration;
+
+    use crate::{
+        game::Event,
+        team_feed::ParsedTeamFeedEventText,
+        utils::{feed_event, game_event},
+        NotRecognized,
+    };
+
+    use super::{merge_timeline, TimelineEntry};
+
+    fn event(index: u16, message: &str, away_score: u16, home_score: u16) -> Event {
+        game_event(serde_json::json!({
+            "away_score": away_score,
+            "home_score": home_score,
+            "index": index,
+            "inning": 9,
+            "inning_side": 1,
+            "message": message,
+        }))
+    }
+
+    #[test]
+    fn interleaves() {
+        let events = [
+            event(0, "Yumi Pratt hit a single.", 3, 6),
+            event(1, "Kelly Vieira hit a double.", 3, 7),
+        ];
+        // Out of order, and one of them doesn't have a day we recognize
+        let result = feed_event(4, 12, "game", "🦀 Crabs vs. 🐝 Bees - FINAL 3-7");
+        let mut later = feed_event(4, 12, "game", "🦀 Crabs vs. 🐝 Bees - FINAL 2-1");
+        later.day = Err(NotRecognized("Some Future Day".into()));
+        later.timestamp = result.timestamp + Duration::hours(1);
+        let mut earlier = feed_event(4, 11, "game", "🐝 Bees vs. 🦀 Crabs - FINAL 1-0");
+        earlier.timestamp = result.timestamp - Duration::days(1);
+        let feed = [result, later, earlier];
+
+        let timeline = merge_timeline(&events, &feed);
+        assert_eq!(timeline.len(), 5);
+        assert!(matches!(
+            timeline[0],
+            TimelineEntry::Feed(ParsedTeamFeedEventText::GameResult { away_score: 1, .. })
+        ));
+        assert_eq!(timeline[1], TimelineEntry::Game(&events[0]));
+        assert_eq!(timeline[2], TimelineEntry::Game(&events[1]));
+        assert!(matches!(
+            timeline[3],
+            TimelineEntry::Feed(ParsedTeamFeedEventText::GameResult { home_score: 7, .. })
+        ));
+        assert!(matches!(
+            timeline[4],
+            TimelineEntry::Feed(ParsedTeamFeedEventText::GameResult { away_score: 2, .. })
+        ));
+    }
+}
@@ -14,10 +14,15 @@ pub mod player_feed;
 pub mod team;
 pub mod team_feed;
 
+#[cfg(test)]
+mod fuzz;
+
 pub use game::Game;
 pub use parsed_event::ParsedEventMessage;
 pub use parsing::{process_event, process_game};
 
+#[cfg(feature = "unrecognized-hook")]
+pub use utils::{clear_unrecognized_hook, set_unrecognized_hook};
 pub use utils::{
     AddedLater, AddedLaterResult, EmptyArrayOr, MaybeRecognizedResult, NotRecognized, RemovedLater,
     RemovedLaterResult,
@@ -64,9 +69,4 @@ impl<'a> UnparsingContext<'a> {
     pub(crate) fn before(&self, event_index: Option<u16>, time: impl Into<Time>) -> bool {
         time.into().before(self.season, self.day, event_index)
     }
-
-    /// Whether this event is after the given time
-    pub(crate) fn after(&self, event_index: Option<u16>, time: impl Into<Time>) -> bool {
-        time.into().after(self.season, self.day, event_index)
-    }
 }
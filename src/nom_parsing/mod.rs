@@ -8,4 +8,6 @@ pub(crate) mod parse_team_feed_event;
 pub(crate) mod shared;
 
 pub use parse::parse_event;
-pub use shared::ParsingContext;
+pub use parse_player_feed_event::PlayerFeedEventParser;
+pub use parse_team_feed_event::TeamFeedEventParser;
+pub use shared::{Error, ParsingContext};
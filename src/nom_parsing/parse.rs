@@ -410,12 +410,15 @@ fn falling_star<'output>() -> impl MyParser<'output, ParsedEventMessage<&'output
     context(
         "Falling Star",
         all_consuming(
-            preceded(
-                tag("<strong>🌠 "),
-                parse_terminated(" is hit by a Falling Star!</strong>"),
+            separated_list1(
+                tag(" "),
+                preceded(
+                    tag("<strong>🌠 "),
+                    parse_terminated(" is hit by a Falling Star!</strong>"),
+                )
+                .and_then(verify_name),
             )
-            .and_then(verify_name)
-            .map(|player_name| ParsedEventMessage::FallingStar { player_name }),
+            .map(|players| ParsedEventMessage::FallingStar { players }),
         ),
     )
 }
@@ -1752,6 +1755,7 @@ mod test {
         enums::{Base, BaseNameVariant, Day, Distance, FairBallType, Place},
         nom_parsing::{shared::verify_name, ParsingContext},
         parsed_event::{EmojiTeam, PlacedPlayer, RunnerAdvance, RunnerOut},
+        utils::assert_unparse_eq,
         ParsedEventMessage, UnparsingContext,
     };
 
@@ -1984,6 +1988,41 @@ mod test {
             },
         };
 
-        assert_eq!(text, event.unparse(unparsing_context, Some(14)));
+        assert_unparse_eq(text, &event.unparse(unparsing_context, Some(14)));
+    }
+
+    #[test]
+    fn falling_star_showers() {
+        let unparsing_context = UnparsingContext {
+            season: 1,
+            day: Some(Day::Day(80)),
+            away_emoji_team: EmojiTeam {
+                emoji: "",
+                name: "",
+            },
+            home_emoji_team: EmojiTeam {
+                emoji: "",
+                name: "",
+            },
+        };
+
+        for players in [
+            vec!["Yumi Pratt"],
+            vec!["Yumi Pratt", "Kelly Vieira", "Bob E. Quiros"],
+        ] {
+            let text = players
+                .iter()
+                .map(|player| format!("<strong>🌠 {player} is hit by a Falling Star!</strong>"))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let (_, event) = super::falling_star().parse(&text).finish().unwrap();
+            assert_eq!(
+                event,
+                ParsedEventMessage::FallingStar {
+                    players: players.clone()
+                }
+            );
+            assert_eq!(event.unparse(unparsing_context, None), text);
+        }
     }
 }
@@ -1,19 +1,22 @@
 use super::shared::{
-    falling_star, feed_event_contained, feed_event_door_prize, feed_event_equipped_door_prize,
-    feed_event_party, feed_event_wither, grow, player_moved, player_positions_swapped,
-    player_relegated, purified, Error, IResult,
+    all_star_selection, attribute_set, consumed_before, entered_superstar_game, falling_star,
+    feed_event_contained, feed_event_door_prize, feed_event_equipped_door_prize, feed_event_party,
+    feed_event_wither, grow, injury, item_modification, modification_list, player_moved,
+    player_positions_swapped, player_relegated, purified, recovery, Error, IResult,
 };
-use crate::feed_event::PlayerGreaterAugment;
+use crate::feed_event::{AttributeEqualPhrasing, ItemEnchantment, PlayerGreaterAugment};
 use crate::{
     enums::{FeedEventType, ModificationType},
-    feed_event::{FeedEvent, FeedEventParseError},
+    feed_event::{FeedEvent, FeedEventParseError, ParseOptions},
     nom_parsing::shared::{
-        emojiless_item, feed_delivery, parse_terminated, sentence_eof, try_from_word, verify_name,
+        attribute_multiplier, emojiless_item, enchant_also, enchant_article, enchant_bonuses,
+        feed_delivery, parse_terminated, sentence_eof, sentence_list, try_from_word, verify_name,
     },
     player_feed::ParsedPlayerFeedEventText,
-    time::{Breakpoints, Timestamp},
+    time::Timestamp,
 };
 use nom::character::complete::u32;
+use nom::multi::separated_list1;
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -24,7 +27,7 @@ use nom::{
     Finish, Parser,
 };
 
-trait PlayerFeedEventParser<'output>:
+pub trait PlayerFeedEventParser<'output>:
     Parser<&'output str, Output = ParsedPlayerFeedEventText<&'output str>, Error = Error<'output>>
 {
 }
@@ -39,60 +42,103 @@ impl<
 {
 }
 
+/// Parses the event along with the text it was parsed from. Every parser consumes the
+/// whole event text, so the source is always `event.text` minus any trailing whitespace.
+pub fn parse_player_feed_event_with_source(
+    event: &FeedEvent,
+) -> (ParsedPlayerFeedEventText<&str>, &str) {
+    (parse_player_feed_event(event), event.text.trim_end())
+}
+
 pub fn parse_player_feed_event(event: &FeedEvent) -> ParsedPlayerFeedEventText<&str> {
+    parse_player_feed_event_with(event, &ParseOptions::default())
+}
+
+pub fn parse_player_feed_event_with<'a>(
+    event: &'a FeedEvent,
+    options: &ParseOptions,
+) -> ParsedPlayerFeedEventText<&'a str> {
+    parse_player_feed_event_text(event, &event.text, options)
+}
+
+/// Parses `text` as though it were `event`'s text, so the result borrows from `text` rather than
+/// the event
+pub(crate) fn parse_player_feed_event_text<'a>(
+    event: &FeedEvent,
+    text: &'a str,
+    options: &ParseOptions,
+) -> ParsedPlayerFeedEventText<&'a str> {
+    // Nothing would match, and FailedParsingText on "" is a confusing thing to get back
+    if text.trim().is_empty() {
+        return ParsedPlayerFeedEventText::ParseError {
+            error: FeedEventParseError::EmptyText,
+            text,
+        };
+    }
+
+    // Some of the parsers backtrack a lot, so don't let a huge text through
+    if text.len() > options.max_text_len {
+        return ParsedPlayerFeedEventText::ParseError {
+            error: FeedEventParseError::TextTooLong { len: text.len() },
+            text,
+        };
+    }
+
     let event_type = match &event.event_type {
         Ok(event_type) => event_type,
         Err(e) => {
             let error = FeedEventParseError::EventTypeNotRecognized(e.clone());
-            return ParsedPlayerFeedEventText::ParseError {
-                error,
-                text: &event.text,
-            };
+            return ParsedPlayerFeedEventText::ParseError { error, text };
         }
     };
 
+    // A stray newline or space at the end would otherwise be leftover text. Unparsing gives
+    // back the trimmed text.
+    let trimmed = text.trim_end();
     let result = match event_type {
-        FeedEventType::Game => game(event).parse(&event.text),
-        FeedEventType::Augment => augment(event).parse(&event.text),
-        FeedEventType::Release => release(event).parse(&event.text),
-        FeedEventType::Season => season(event).parse(event.text.as_str()),
-        FeedEventType::Election => election(event).parse(&event.text),
-        FeedEventType::Roster => roster(event).parse(event.text.as_str()),
-        // TODO More descriptive error message
-        FeedEventType::Lottery => fail().parse(event.text.as_str()),
-        FeedEventType::Maintenance => fail().parse(event.text.as_str()),
+        FeedEventType::Game => game(event).parse(trimmed),
+        FeedEventType::Augment => augment(event).parse(trimmed),
+        FeedEventType::Release => release(event).parse(trimmed),
+        FeedEventType::Season => season(event).parse(trimmed),
+        FeedEventType::Election => election(event).parse(trimmed),
+        FeedEventType::Roster => roster(event).parse(trimmed),
+        // Players don't get these, so there's nothing to parse
+        FeedEventType::Lottery => context("Lottery Feed Event", fail()).parse(trimmed),
+        FeedEventType::Maintenance => context("Maintenance Feed Event", fail()).parse(trimmed),
     };
     match result.finish() {
         Ok(("", output)) => output,
         Ok((leftover, _)) => {
             tracing::error!(
                 "{event_type} feed event parsed had leftover: {leftover} from {}",
-                &event.text
+                trimmed
             );
             let error = FeedEventParseError::FailedParsingText {
                 event_type: *event_type,
-                text: event.text.clone(),
+                text: trimmed.to_string(),
+                consumed: consumed_before(trimmed, leftover),
             };
-            ParsedPlayerFeedEventText::ParseError {
-                error,
-                text: &event.text,
-            }
+            ParsedPlayerFeedEventText::ParseError { error, text }
         }
         Err(e) => {
+            // The first error is the innermost one, i.e. where parsing actually got stuck
+            let consumed = e
+                .errors
+                .first()
+                .map(|(remaining, _)| consumed_before(trimmed, remaining))
+                .unwrap_or_default();
             let error = FeedEventParseError::FailedParsingText {
                 event_type: *event_type,
-                text: event.text.clone(),
+                text: trimmed.to_string(),
+                consumed,
             };
             tracing::error!("Parse error: {e:?}");
-            ParsedPlayerFeedEventText::ParseError {
-                error,
-                text: &event.text,
-            }
+            ParsedPlayerFeedEventText::ParseError { error, text }
         }
     }
 }
 
-fn game<'output>(event: &'output FeedEvent) -> impl PlayerFeedEventParser<'output> {
+fn game<'output>(event: &FeedEvent) -> impl PlayerFeedEventParser<'output> + use<'_, 'output> {
     context(
         "Game Feed Event",
         alt((
@@ -111,6 +157,8 @@ fn game<'output>(event: &'output FeedEvent) -> impl PlayerFeedEventParser<'outpu
                     outcome,
                 }
             }),
+            injury(event).map(|player_name| ParsedPlayerFeedEventText::Injury { player_name }),
+            recovery(event).map(|player_name| ParsedPlayerFeedEventText::Recovery { player_name }),
             retirement(true),
             feed_event_wither
                 .map(|player_name| ParsedPlayerFeedEventText::CorruptedByWither { player_name }),
@@ -126,21 +174,25 @@ fn game<'output>(event: &'output FeedEvent) -> impl PlayerFeedEventParser<'outpu
     )
 }
 
-fn augment<'output>(event: &'output FeedEvent) -> impl PlayerFeedEventParser<'output> {
+fn augment<'output>(event: &FeedEvent) -> impl PlayerFeedEventParser<'output> + use<'_, 'output> {
     context(
         "Augment Feed Event",
         alt((
-            attribute_gain(),
-            modification(),
-            enchantment_s1a(),
+            // Before the attribute changes, which would read an "and also gained" clause as one
             enchantment_s1b(),
             enchantment_s2(),
             enchantment_compensatory(),
-            attribute_equal(event),
+            attribute_gain(),
+            modification(),
+            enchantment_s1a(),
+            disenchantment(),
+            attribute_equal(),
             recompose(event),
             take_the_mound(),
             take_the_plate(),
             swap_places(),
+            // Its own fixed wording, so it can't be mistaken for a swap or roster move
+            retirement(false),
             purified.map(
                 |(player_name, outcome)| ParsedPlayerFeedEventText::Purified {
                     player_name,
@@ -150,12 +202,15 @@ fn augment<'output>(event: &'output FeedEvent) -> impl PlayerFeedEventParser<'ou
             player_positions_swapped
                 .map(|swap| ParsedPlayerFeedEventText::PlayerPositionsSwapped { swap }),
             grow.map(|grow| ParsedPlayerFeedEventText::PlayerGrow { grow }),
+            attribute_set.map(|set| ParsedPlayerFeedEventText::AttributeSet { set }),
+            item_modification
+                .map(|modification| ParsedPlayerFeedEventText::ItemModification { modification }),
             fail(),
         )),
     )
 }
 
-fn release<'output>(_event: &'output FeedEvent) -> impl PlayerFeedEventParser<'output> {
+fn release<'output>(_event: &FeedEvent) -> impl PlayerFeedEventParser<'output> + use<'_, 'output> {
     context(
         "Release Feed Event",
         alt(
@@ -165,81 +220,80 @@ fn release<'output>(_event: &'output FeedEvent) -> impl PlayerFeedEventParser<'o
     )
 }
 
-fn season<'output>(_event: &'output FeedEvent) -> impl PlayerFeedEventParser<'output> {
+fn season<'output>(_event: &FeedEvent) -> impl PlayerFeedEventParser<'output> + use<'_, 'output> {
     context(
         "Season Feed Event",
-        alt((retirement(false), seasonal_durability_loss)),
+        alt((
+            retirement(false),
+            seasonal_durability_loss,
+            all_star_selection
+                .map(|(player, team)| ParsedPlayerFeedEventText::AllStarSelection { player, team }),
+            entered_superstar_game
+                .map(|player| ParsedPlayerFeedEventText::EnteredSuperstarGame { player }),
+        )),
     )
 }
 
+/// Unlike the team feed there's only ever the one player's gain
 fn attribute_gain<'output>() -> impl PlayerFeedEventParser<'output> {
-    (
-        preceded(opt(tag(" ")), parse_terminated(" gained +")),
+    sentence_list((
+        parse_terminated(" gained +"),
         i16,
-        delimited(tag(" "), try_from_word, tag(".")),
-    )
-        .map(
-            |(player_name, amount, attribute)| ParsedPlayerFeedEventText::AttributeChanges {
-                player_name,
-                amount,
-                attribute,
-            },
-        )
+        preceded(tag(" "), try_from_word),
+        attribute_multiplier,
+    ))
+    .map_opt(|changes| {
+        let [(player_name, amount, attribute, multiplier)] = changes.try_into().ok()?;
+        Some(ParsedPlayerFeedEventText::AttributeChanges {
+            player_name,
+            amount,
+            attribute,
+            multiplier,
+        })
+    })
 }
 
-fn attribute_equal<'output>(event: &'output FeedEvent) -> impl PlayerFeedEventParser<'output> {
+/// Accepts any of the phrasings no matter when the event is from, since the breakpoints between
+/// them aren't exact
+fn attribute_equal<'output>() -> impl PlayerFeedEventParser<'output> {
     |input| {
-        if event.after(Breakpoints::Season3) {
-            (
-                parse_terminated("'s "),
-                try_from_word,
-                delimited(tag(" was set to their "), try_from_word, tag(".")),
-            )
-                .map(|(player_name, changing_attribute, value_attribute)| {
-                    ParsedPlayerFeedEventText::AttributeEquals {
-                        player_name,
-                        changing_attribute,
-                        value_attribute,
-                    }
-                })
-                .parse(input)
-        } else if event.after(Breakpoints::S1AttributeEqualChange) {
-            (
-                parse_terminated("'s "),
-                try_from_word,
-                delimited(
-                    tag(" became equal to their current base "),
-                    try_from_word,
-                    tag("."),
-                ),
-            )
-                .map(|(player_name, changing_attribute, value_attribute)| {
-                    ParsedPlayerFeedEventText::AttributeEquals {
-                        player_name,
-                        changing_attribute,
-                        value_attribute,
-                    }
-                })
-                .parse(input)
+        let (input, (player_name, changing_attribute)) =
+            (parse_terminated("'s "), try_from_word).parse(input)?;
+        let (input, set_to) = alt((
+            tag(" was set to ").map(|_| true),
+            tag(" became equal to ").map(|_| false),
+        ))
+        .parse(input)?;
+        let (input, value_player) = alt((
+            tag("their ").map(|_| None),
+            parse_terminated("'s ").map(Some),
+        ))
+        .parse(input)?;
+        let (input, phrasing) = if set_to {
+            (input, AttributeEqualPhrasing::SetTo)
         } else {
-            (
-                parse_terminated("'s "),
-                try_from_word,
-                delimited(tag(" was set to their "), try_from_word, tag(".")),
-            )
-                .map(|(player_name, changing_attribute, value_attribute)| {
-                    ParsedPlayerFeedEventText::AttributeEquals {
-                        player_name,
-                        changing_attribute,
-                        value_attribute,
-                    }
-                })
-                .parse(input)
-        }
+            alt((
+                tag("current base ").map(|_| AttributeEqualPhrasing::BecameEqualToCurrentBase),
+                tag("base ").map(|_| AttributeEqualPhrasing::BecameEqualToBase),
+            ))
+            .parse(input)?
+        };
+        let (input, value_attribute) = terminated(try_from_word, tag(".")).parse(input)?;
+
+        Ok((
+            input,
+            ParsedPlayerFeedEventText::AttributeEquals {
+                player_name,
+                changing_attribute,
+                value_player,
+                value_attribute,
+                phrasing: Some(phrasing),
+            },
+        ))
     }
 }
 
-fn recompose<'output>(event: &'output FeedEvent) -> impl PlayerFeedEventParser<'output> {
+fn recompose<'output>(event: &FeedEvent) -> impl PlayerFeedEventParser<'output> + use<'_, 'output> {
     |input: &'output str| {
         if event.timestamp > Timestamp::Season3RecomposeChange.timestamp() {
             (
@@ -276,10 +330,10 @@ fn enchantment_s1a<'output>() -> impl PlayerFeedEventParser<'output> {
             |(player_name, item, amount, attribute)| ParsedPlayerFeedEventText::Enchantment {
                 player_name,
                 item,
-                amount,
-                attribute,
-                enchant_two: None,
+                article: None,
+                enchants: vec![(amount, attribute)],
                 compensatory: false,
+                also: false,
             },
         )
 }
@@ -292,18 +346,19 @@ fn enchantment_s1b<'output>() -> impl PlayerFeedEventParser<'output> {
         ),
         emojiless_item,
         delimited(tag(" gained a +"), u8, tag(" ")),
-        terminated(try_from_word, tag(" bonus.")),
+        terminated(try_from_word, tag(" bonus")),
+        terminated(enchant_also, tag(".")),
     )
-        .map(
-            |(player_name, item, amount, attribute)| ParsedPlayerFeedEventText::Enchantment {
+        .map(|(player_name, item, amount, attribute, also)| {
+            ParsedPlayerFeedEventText::Enchantment {
                 player_name,
                 item,
-                amount,
-                attribute,
-                enchant_two: None,
+                article: None,
+                enchants: std::iter::once((amount, attribute)).chain(also).collect(),
                 compensatory: false,
-            },
-        )
+                also: also.is_some(),
+            }
+        })
 }
 
 fn enchantment_s2<'output>() -> impl PlayerFeedEventParser<'output> {
@@ -313,29 +368,71 @@ fn enchantment_s2<'output>() -> impl PlayerFeedEventParser<'output> {
             parse_terminated("'s "),
         ),
         emojiless_item,
-        preceded(
-            (tag(" was enchanted with "), opt(tag("a ")), tag("+")),
-            separated_pair(u8, tag(" "), try_from_word),
-        ),
         delimited(
-            tag(" and +"),
-            separated_pair(u8, tag(" "), try_from_word),
+            tag(" was enchanted with "),
+            (enchant_article, enchant_bonuses, enchant_also),
             tag("."),
         ),
     )
-        .map(|(player_name, item, (amount, attribute), enchant_two)| {
+        .map(|(player_name, item, (article, mut enchants, also))| {
+            enchants.extend(also);
             ParsedPlayerFeedEventText::Enchantment {
+                player_name,
+                item,
+                article,
+                enchants,
+                compensatory: false,
+                also: also.is_some(),
+            }
+        })
+}
+
+/// Speculative, like the team feed's version
+fn disenchantment<'output>() -> impl PlayerFeedEventParser<'output> {
+    (
+        parse_terminated("'s "),
+        emojiless_item,
+        delimited(
+            tag(" lost its +"),
+            separated_pair(u8, tag(" "), try_from_word),
+            tag(" bonus."),
+        ),
+    )
+        .map(|(player_name, item, (amount, attribute))| {
+            ParsedPlayerFeedEventText::Disenchantment {
                 player_name,
                 item,
                 amount,
                 attribute,
-                enchant_two: Some(enchant_two),
-                compensatory: false,
             }
         })
 }
 
 fn enchantment_compensatory<'output>() -> impl PlayerFeedEventParser<'output> {
+    separated_list1(tag(" "), compensatory_item_enchantment).map(|mut enchantments| {
+        if enchantments.len() == 1 {
+            let ItemEnchantment {
+                player_name,
+                item,
+                article,
+                enchants,
+                also,
+            } = enchantments.remove(0);
+            ParsedPlayerFeedEventText::Enchantment {
+                player_name,
+                item,
+                article,
+                enchants,
+                compensatory: true,
+                also,
+            }
+        } else {
+            ParsedPlayerFeedEventText::CompensatoryEnchantments { enchantments }
+        }
+    })
+}
+
+fn compensatory_item_enchantment(input: &str) -> IResult<'_, &str, ItemEnchantment<&str>> {
     (
         preceded(
             tag("The Compensatory Enchantment was a success! "),
@@ -343,36 +440,33 @@ fn enchantment_compensatory<'output>() -> impl PlayerFeedEventParser<'output> {
         ),
         emojiless_item,
         alt((
-            (
-                preceded(
-                    (tag(" was enchanted with "), opt(tag("a ")), tag("+")),
-                    separated_pair(u8, tag(" "), try_from_word),
-                ),
-                delimited(
-                    tag(" and +"),
+            delimited(
+                tag(" was enchanted with "),
+                (enchant_article, enchant_bonuses, enchant_also),
+                tag("."),
+            ),
+            delimited(
+                tag(" gained a +"),
+                (
                     separated_pair(u8, tag(" "), try_from_word),
-                    tag("."),
+                    preceded(tag(" bonus"), enchant_also),
                 ),
+                tag("."),
             )
-                .map(|((amount, attribute), second)| (amount, attribute, Some(second))),
-            (delimited(
-                tag(" gained a +"),
-                separated_pair(u8, tag(" "), try_from_word),
-                tag(" bonus."),
-            )
-            .map(|(amount, attribute)| (amount, attribute, None))),
+            .map(|(bonus, also)| (None, vec![bonus], also)),
         )),
     )
-        .map(|(player_name, item, (amount, attribute, enchant_two))| {
-            ParsedPlayerFeedEventText::Enchantment {
+        .map(|(player_name, item, (article, mut enchants, also))| {
+            enchants.extend(also);
+            ItemEnchantment {
                 player_name,
                 item,
-                amount,
-                attribute,
-                enchant_two,
-                compensatory: true,
+                article,
+                enchants,
+                also: also.is_some(),
             }
         })
+        .parse(input)
 }
 
 fn take_the_mound<'output>() -> impl PlayerFeedEventParser<'output> {
@@ -418,29 +512,26 @@ fn modification<'output>() -> impl PlayerFeedEventParser<'output> {
                 .map(ModificationType::new)
                 .parse(input)?;
             let (input, _) = (tag(player_name), tag(" gained the ")).parse(input)?;
-            let (input, modification) = parse_terminated(" Modification.")
-                .map(ModificationType::new)
-                .parse(input)?;
+            let (input, (modification, additional_modifications)) = modification_list(input)?;
             Ok((
                 input,
                 ParsedPlayerFeedEventText::Modification {
                     player_name,
                     modification,
+                    additional_modifications,
                     lost_modification: Some(lost_modification),
                 },
             ))
         } else {
-            let (input, (player_name, modification)) = (
-                parse_terminated(" gained the "),
-                parse_terminated(" Modification.").map(ModificationType::new),
-            )
-                .parse(input)?;
+            let (input, (player_name, (modification, additional_modifications))) =
+                (parse_terminated(" gained the "), modification_list).parse(input)?;
 
             Ok((
                 input,
                 ParsedPlayerFeedEventText::Modification {
                     player_name,
                     modification,
+                    additional_modifications,
                     lost_modification: None,
                 },
             ))
@@ -512,7 +603,7 @@ fn seasonal_durability_loss_blocked(
     ))
 }
 
-fn election<'output>(_event: &'output FeedEvent) -> impl PlayerFeedEventParser<'output> {
+fn election<'output>(_event: &FeedEvent) -> impl PlayerFeedEventParser<'output> + use<'_, 'output> {
     context(
         "Election Feed Event",
         alt((
@@ -604,7 +695,7 @@ fn player_retroactive_greater_augment_result(
     ))
 }
 
-fn roster<'output>(_event: &'output FeedEvent) -> impl PlayerFeedEventParser<'output> {
+fn roster<'output>(_event: &FeedEvent) -> impl PlayerFeedEventParser<'output> + use<'_, 'output> {
     context(
         "Roster Feed Event",
         alt((
@@ -3,35 +3,39 @@ use super::shared::{
     feed_event_door_prize, feed_event_equipped_door_prize, feed_event_party, feed_event_wither,
     parse_until_period_eof, player_positions_swapped, purified, Error, IResult,
 };
-use crate::feed_event::{AttributeChange, GreaterAugment};
+use crate::feed_event::{AttributeChange, AttributeOperation, GreaterAugment, ItemEnchantment};
 use crate::nom_parsing::shared::{
-    active_slot, falling_star, feed_event_effloresce, feed_event_efflorescence_growth, grow,
-    parse_until_exclamation_point_eof, player_moved, player_relegated,
+    active_slot, all_star_selection, attribute_set, consumed_before, entered_superstar_game,
+    falling_star, feed_event_effloresce, feed_event_efflorescence_growth, grow, injury,
+    item_modification, modification_list, parse_and, parse_until_exclamation_point_eof,
+    player_moved, player_relegated, recovery, thousands_separated_u32,
 };
 use crate::parsed_event::{EmojiPlayer, EmojiTeam};
 use crate::{
-    enums::{FeedEventType, ModificationType},
-    feed_event::{FeedEvent, FeedEventParseError},
+    enums::{Currency, FeedEventType, ModificationType},
+    feed_event::{FeedEvent, FeedEventParseError, ParseOptions},
     nom_parsing::shared::{
-        emojiless_item, feed_delivery, parse_terminated, sentence_eof, try_from_word, verify_name,
+        attribute_multiplier, emojiless_item, enchant_also, enchant_article, enchant_bonuses,
+        feed_delivery, parse_terminated, parse_terminated_in_sentence, sentence_eof, sentence_list,
+        try_from_word, verify_name,
     },
-    team_feed::ParsedTeamFeedEventText,
+    team_feed::{AttributeTarget, GameAnnotation, ParsedTeamFeedEventText, ScoreSeparator},
     time::{Breakpoints, Timestamp},
 };
 use nom::bytes::complete::take_while;
-use nom::combinator::{eof, verify};
-use nom::multi::{many1, separated_list1};
+use nom::combinator::{all_consuming, eof, verify};
+use nom::multi::separated_list1;
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{i16, u32, u8},
+    character::complete::{i16, u16, u32, u8},
     combinator::{cond, fail, opt},
     error::context,
     sequence::{delimited, preceded, separated_pair, terminated},
     Finish, Parser,
 };
 
-trait TeamFeedEventParser<'output>:
+pub trait TeamFeedEventParser<'output>:
     Parser<&'output str, Output = ParsedTeamFeedEventText<&'output str>, Error = Error<'output>>
 {
 }
@@ -46,59 +50,102 @@ impl<
 {
 }
 
+/// Parses the event along with the text it was parsed from. Every parser consumes the
+/// whole event text, so the source is always `event.text` minus any trailing whitespace.
+pub fn parse_team_feed_event_with_source(
+    event: &FeedEvent,
+) -> (ParsedTeamFeedEventText<&str>, &str) {
+    (parse_team_feed_event(event), event.text.trim_end())
+}
+
 pub fn parse_team_feed_event(event: &FeedEvent) -> ParsedTeamFeedEventText<&str> {
+    parse_team_feed_event_with(event, &ParseOptions::default())
+}
+
+pub fn parse_team_feed_event_with<'a>(
+    event: &'a FeedEvent,
+    options: &ParseOptions,
+) -> ParsedTeamFeedEventText<&'a str> {
+    parse_team_feed_event_text(event, &event.text, options)
+}
+
+/// Parses `text` as though it were `event`'s text, so the result borrows from `text` rather than
+/// the event
+pub(crate) fn parse_team_feed_event_text<'a>(
+    event: &FeedEvent,
+    text: &'a str,
+    options: &ParseOptions,
+) -> ParsedTeamFeedEventText<&'a str> {
+    // Nothing would match, and FailedParsingText on "" is a confusing thing to get back
+    if text.trim().is_empty() {
+        return ParsedTeamFeedEventText::ParseError {
+            error: FeedEventParseError::EmptyText,
+            text,
+        };
+    }
+
+    // Some of the parsers backtrack a lot, so don't let a huge text through
+    if text.len() > options.max_text_len {
+        return ParsedTeamFeedEventText::ParseError {
+            error: FeedEventParseError::TextTooLong { len: text.len() },
+            text,
+        };
+    }
+
     let event_type = match &event.event_type {
         Ok(event_type) => event_type,
         Err(e) => {
             let error = FeedEventParseError::EventTypeNotRecognized(e.clone());
-            return ParsedTeamFeedEventText::ParseError {
-                error,
-                text: &event.text,
-            };
+            return ParsedTeamFeedEventText::ParseError { error, text };
         }
     };
 
+    // A stray newline or space at the end would otherwise be leftover text. Unparsing gives
+    // back the trimmed text.
+    let trimmed = text.trim_end();
     let result = match event_type {
-        FeedEventType::Game => game(event).parse(&event.text),
-        FeedEventType::Augment => augment(event).parse(&event.text),
-        FeedEventType::Release => release(event).parse(&event.text),
-        FeedEventType::Season => season(event).parse(event.text.as_str()),
-        FeedEventType::Lottery => lottery().parse(event.text.as_str()),
-        FeedEventType::Maintenance => maintenance().parse(event.text.as_str()),
-        FeedEventType::Roster => roster().parse(event.text.as_str()),
-        FeedEventType::Election => election().parse(event.text.as_str()),
+        FeedEventType::Game => game(event).parse(trimmed),
+        FeedEventType::Augment => augment(event).parse(trimmed),
+        FeedEventType::Release => release(event).parse(trimmed),
+        FeedEventType::Season => season(event).parse(trimmed),
+        FeedEventType::Lottery => lottery().parse(trimmed),
+        FeedEventType::Maintenance => maintenance().parse(trimmed),
+        FeedEventType::Roster => roster().parse(trimmed),
+        FeedEventType::Election => election().parse(trimmed),
     };
     match result.finish() {
         Ok(("", output)) => output,
         Ok((leftover, _)) => {
             tracing::error!(
                 "{event_type} feed event parsed had leftover: {leftover} from {}",
-                &event.text
+                trimmed
             );
             let error = FeedEventParseError::FailedParsingText {
                 event_type: *event_type,
-                text: event.text.clone(),
+                text: trimmed.to_string(),
+                consumed: consumed_before(trimmed, leftover),
             };
-            ParsedTeamFeedEventText::ParseError {
-                error,
-                text: &event.text,
-            }
+            ParsedTeamFeedEventText::ParseError { error, text }
         }
         Err(e) => {
+            // The first error is the innermost one, i.e. where parsing actually got stuck
+            let consumed = e
+                .errors
+                .first()
+                .map(|(remaining, _)| consumed_before(trimmed, remaining))
+                .unwrap_or_default();
             let error = FeedEventParseError::FailedParsingText {
                 event_type: *event_type,
-                text: event.text.clone(),
+                text: trimmed.to_string(),
+                consumed,
             };
             tracing::error!("Parse error: {e:?}");
-            ParsedTeamFeedEventText::ParseError {
-                error,
-                text: &event.text,
-            }
+            ParsedTeamFeedEventText::ParseError { error, text }
         }
     }
 }
 
-fn game(event: &FeedEvent) -> impl TeamFeedEventParser<'_> {
+fn game<'output>(event: &FeedEvent) -> impl TeamFeedEventParser<'output> + use<'_, 'output> {
     context(
         "Game Feed Event",
         alt((
@@ -119,8 +166,11 @@ fn game(event: &FeedEvent) -> impl TeamFeedEventParser<'_> {
                     outcome,
                 }
             }),
+            injury(event).map(|player_name| ParsedTeamFeedEventText::Injury { player_name }),
+            recovery(event).map(|player_name| ParsedTeamFeedEventText::Recovery { player_name }),
             feed_event_party.map(|party| ParsedTeamFeedEventText::Party { party }),
             prosperous(),
+            payout(),
             retirement(true),
             feed_event_wither
                 .map(|player_name| ParsedTeamFeedEventText::CorruptedByWither { player_name }),
@@ -145,21 +195,27 @@ fn game(event: &FeedEvent) -> impl TeamFeedEventParser<'_> {
     )
 }
 
-fn augment(event: &FeedEvent) -> impl TeamFeedEventParser<'_> {
+fn augment<'output>(event: &FeedEvent) -> impl TeamFeedEventParser<'output> + use<'_, 'output> {
     context(
         "Augment Feed Event",
         alt((
-            attribute_gain(),
-            modification(),
-            enchantment_s1a(),
+            // Before the attribute changes, which would read an "and also gained" clause as one
             enchantment_s1b(),
             enchantment_s2(),
             enchantment_compensatory(),
+            team_attribute_gain(),
+            attribute_operations(),
+            attribute_gain(),
+            modification(),
+            enchantment_s1a(),
+            disenchantment(),
             multiple_attribute_equal(event),
             recompose(event),
             take_the_mound(),
             take_the_plate(),
             swap_places(),
+            // Its own fixed wording, so it can't be mistaken for a swap or roster move
+            retirement(false),
             purified.map(|(player_name, outcome)| ParsedTeamFeedEventText::Purified {
                 player_name,
                 outcome,
@@ -167,6 +223,12 @@ fn augment(event: &FeedEvent) -> impl TeamFeedEventParser<'_> {
             player_positions_swapped
                 .map(|swap| ParsedTeamFeedEventText::PlayerPositionsSwapped { swap }),
             grow.map(|grow| ParsedTeamFeedEventText::PlayerGrow { grow }),
+            // Nested because alt only takes 21 parsers
+            alt((
+                attribute_set.map(|set| ParsedTeamFeedEventText::AttributeSet { set }),
+                item_modification
+                    .map(|modification| ParsedTeamFeedEventText::ItemModification { modification }),
+            )),
             fail(),
         )),
     )
@@ -174,15 +236,22 @@ fn augment(event: &FeedEvent) -> impl TeamFeedEventParser<'_> {
 
 fn game_result<'output>() -> impl TeamFeedEventParser<'output> {
     |input| {
-        let (input, away_team) = parse_terminated(" vs. ")
-            .and_then(emoji_team_eof_maybe_no_space)
-            .parse(input)?;
-        let (input, home_team) = parse_terminated(" - FINAL ")
-            .and_then(emoji_team_eof)
+        // Team names can contain " vs. " (and the early season 1 bug mangles their spacing),
+        // so only split on a " vs. " that has a valid home team and score after it. If more than
+        // one split would work (a " vs. " followed by an emoji inside a name), the first one wins.
+        let (input, (away_team, (home_team, away_score, score_separator, home_score, annotation))) =
+            parse_and(
+                all_consuming((
+                    parse_terminated(" - FINAL ").and_then(emoji_team_eof),
+                    u16,
+                    score_separator,
+                    u16,
+                    opt(preceded(tag(" "), game_annotation)),
+                )),
+                " vs. ",
+            )
             .parse(input)?;
-        let (input, away_score) = u8.parse(input)?;
-        let (input, _) = tag("-").parse(input)?;
-        let (input, home_score) = u8.parse(input)?;
+        let (_, away_team) = emoji_team_eof_maybe_no_space(away_team)?;
 
         Ok((
             input,
@@ -191,11 +260,37 @@ fn game_result<'output>() -> impl TeamFeedEventParser<'output> {
                 away_team,
                 home_score,
                 away_score,
+                annotation,
+                score_separator,
             },
         ))
     }
 }
 
+/// Speculative: only "-" has been captured, so the other separators are guesses.
+fn score_separator(input: &str) -> IResult<'_, &str, Option<ScoreSeparator>> {
+    alt((
+        tag("-").map(|_| None),
+        tag(" - ").map(|_| Some(ScoreSeparator::SpacedHyphen)),
+        tag("–").map(|_| Some(ScoreSeparator::EnDash)),
+        tag("—").map(|_| Some(ScoreSeparator::EmDash)),
+    ))
+    .parse(input)
+}
+
+/// Speculative: no such note has been captured, so "(N innings)" and "(forfeit)" are guesses.
+fn game_annotation(input: &str) -> IResult<'_, &str, GameAnnotation> {
+    delimited(
+        tag("("),
+        alt((
+            terminated(u8, tag(" innings")).map(GameAnnotation::ExtraInnings),
+            tag("forfeit").map(|_| GameAnnotation::Forfeit),
+        )),
+        tag(")"),
+    )
+    .parse(input)
+}
+
 fn photo_contest<'output>() -> impl TeamFeedEventParser<'output> {
     alt((photo_contest_without_name(), photo_contest_with_name()))
 }
@@ -247,7 +342,25 @@ fn prosperous<'output>() -> impl TeamFeedEventParser<'output> {
     }
 }
 
-fn release<'output>(_event: &'output FeedEvent) -> impl TeamFeedEventParser<'output> {
+/// Amounts over 999 have thousands separators. Speculative: no such line has been captured, so
+/// "received a payout of" is a guess.
+fn payout<'output>() -> impl TeamFeedEventParser<'output> {
+    (
+        parse_terminated(" received a payout of ").and_then(verify_name),
+        thousands_separated_u32,
+        delimited(tag(" "), tag("🪙").map(|_| Currency::Tokens), tag(".")),
+    )
+        .map(|(recipient, (amount, thousands_separators), currency)| {
+            ParsedTeamFeedEventText::Payout {
+                recipient,
+                amount,
+                thousands_separators,
+                currency,
+            }
+        })
+}
+
+fn release<'output>(_event: &FeedEvent) -> impl TeamFeedEventParser<'output> + use<'_, 'output> {
     context(
         "Release Feed Event",
         alt(
@@ -257,8 +370,28 @@ fn release<'output>(_event: &'output FeedEvent) -> impl TeamFeedEventParser<'out
     )
 }
 
-fn season<'output>(_event: &'output FeedEvent) -> impl TeamFeedEventParser<'output> {
-    context("Season Feed Event", alt((retirement(false),)))
+fn season<'output>(_event: &FeedEvent) -> impl TeamFeedEventParser<'output> + use<'_, 'output> {
+    context(
+        "Season Feed Event",
+        alt((
+            retirement(false),
+            season_began(),
+            postseason_began(),
+            all_star_selection
+                .map(|(player, team)| ParsedTeamFeedEventText::AllStarSelection { player, team }),
+            entered_superstar_game
+                .map(|player| ParsedTeamFeedEventText::EnteredSuperstarGame { player }),
+        )),
+    )
+}
+
+fn season_began<'output>() -> impl TeamFeedEventParser<'output> {
+    delimited(tag("Season "), u32, tag(" has begun."))
+        .map(|season| ParsedTeamFeedEventText::SeasonBegan { season })
+}
+
+fn postseason_began<'output>() -> impl TeamFeedEventParser<'output> {
+    tag("The Postseason begins.").map(|_| ParsedTeamFeedEventText::PostseasonBegan)
 }
 
 fn lottery<'output>() -> impl TeamFeedEventParser<'output> {
@@ -326,10 +459,35 @@ fn roster<'output>() -> impl TeamFeedEventParser<'output> {
             ),
             player_relegated
                 .map(|player_name| ParsedTeamFeedEventText::PlayerRelegated { player_name }),
+            roster_move,
         )),
     )
 }
 
+/// Speculative: no such line has been captured, so "was promoted/demoted from the ... to the ..."
+/// is a guess.
+fn roster_move(input: &str) -> IResult<'_, &str, ParsedTeamFeedEventText<&str>> {
+    let (input, team_emoji) = terminated(emoji, tag(" ")).parse(input)?;
+    let (input, (player_name, promoted)) = alt((
+        parse_terminated(" was promoted from the ").map(|name| (name, true)),
+        parse_terminated(" was demoted from the ").map(|name| (name, false)),
+    ))
+    .parse(input)?;
+    let (input, from) = try_from_word.parse(input)?;
+    let (input, to) = delimited(tag(" to the "), try_from_word, tag(".")).parse(input)?;
+
+    Ok((
+        input,
+        ParsedTeamFeedEventText::RosterMove {
+            team_emoji,
+            player_name,
+            promoted,
+            from,
+            to,
+        },
+    ))
+}
+
 fn election<'output>() -> impl TeamFeedEventParser<'output> {
     context("Election Feed Event", alt((callup, greater_augment)))
 }
@@ -409,22 +567,109 @@ fn greater_augment(input: &str) -> IResult<'_, &str, ParsedTeamFeedEventText<&st
 }
 
 fn attribute_gain<'output>() -> impl TeamFeedEventParser<'output> {
-    many1(
+    sentence_list(
         (
-            preceded(opt(tag(" ")), parse_terminated(" gained +")),
+            parse_terminated_in_sentence(" gained +"),
             i16,
-            delimited(tag(" "), try_from_word, tag(".")),
+            preceded(tag(" "), try_from_word),
+            attribute_multiplier,
         )
-            .map(|(player_name, amount, attribute)| AttributeChange {
-                player_name,
-                amount,
-                attribute,
-            }),
+            .map(
+                |(player_name, amount, attribute, multiplier)| AttributeChange {
+                    player_name,
+                    amount,
+                    attribute,
+                    multiplier,
+                },
+            ),
     )
     .map(|changes| ParsedTeamFeedEventText::AttributeChanges { changes })
 }
 
-fn multiple_attribute_equal(event: &FeedEvent) -> impl TeamFeedEventParser<'_> {
+/// Only for events that mix kinds of sentences, so the single-kind parsers still get the rest
+fn attribute_operations<'output>() -> impl TeamFeedEventParser<'output> {
+    let change = |player_name, amount, attribute, multiplier| AttributeChange {
+        player_name,
+        amount,
+        attribute,
+        multiplier,
+    };
+    let operation = alt((
+        (
+            parse_terminated_in_sentence(" gained +"),
+            i16,
+            preceded(tag(" "), try_from_word),
+            attribute_multiplier,
+        )
+            .map(move |(player_name, amount, attribute, multiplier)| {
+                AttributeOperation::Gain(change(player_name, amount, attribute, multiplier))
+            }),
+        (
+            parse_terminated_in_sentence(" lost "),
+            i16,
+            preceded(tag(" "), try_from_word),
+        )
+            .map(move |(player_name, amount, attribute)| {
+                AttributeOperation::Loss(change(player_name, amount, attribute, None))
+            }),
+        (
+            parse_terminated_in_sentence("'s "),
+            try_from_word,
+            alt((
+                tag(" became equal to their current base ").map(|_| true),
+                tag(" became equal to their base ").map(|_| false),
+            )),
+            try_from_word,
+        )
+            .map(
+                |(player_name, changing_attribute, current, value_attribute)| {
+                    AttributeOperation::Equal {
+                        player_name,
+                        changing_attribute,
+                        value_attribute,
+                        current,
+                    }
+                },
+            ),
+    ));
+
+    verify(
+        sentence_list(operation),
+        |operations: &Vec<AttributeOperation<&str>>| {
+            !operations
+                .iter()
+                .all(|operation| matches!(operation, AttributeOperation::Gain(_)))
+                && !operations
+                    .iter()
+                    .all(|operation| matches!(operation, AttributeOperation::Equal { .. }))
+        },
+    )
+    .map(|operations| ParsedTeamFeedEventText::AttributeOperations { operations })
+}
+
+fn team_attribute_gain<'output>() -> impl TeamFeedEventParser<'output> {
+    (
+        alt((
+            tag("All players").map(|_| AttributeTarget::AllPlayers),
+            // Speculative: only "All players" has been seen. These two are guesses.
+            tag("All batters").map(|_| AttributeTarget::Lineup),
+            tag("All pitchers").map(|_| AttributeTarget::Rotation),
+        )),
+        preceded(tag(" gained +"), i16),
+        delimited(tag(" "), try_from_word, tag(".")),
+    )
+        .map(
+            |(target, amount, attribute)| ParsedTeamFeedEventText::TeamAttributeChange {
+                target,
+                amount,
+                attribute,
+            },
+        )
+}
+
+fn multiple_attribute_equal<'output>(
+    event: &FeedEvent,
+) -> impl TeamFeedEventParser<'output> + use<'_, 'output> {
     |input| {
         if event.after(Breakpoints::Season3) {
             (
@@ -454,27 +699,23 @@ fn multiple_attribute_equal(event: &FeedEvent) -> impl TeamFeedEventParser<'_> {
                     (
                         parse_terminated("'s "),
                         try_from_word,
-                        delimited(
-                            tag(" became equal to their current base "),
-                            try_from_word,
-                            tag("."),
-                        ),
+                        preceded(tag(" became equal to their current base "), try_from_word),
                     )
                         .parse(input)
                 } else {
                     (
                         parse_terminated("'s "),
                         try_from_word,
-                        delimited(tag(" became equal to their base "), try_from_word, tag(".")),
+                        preceded(tag(" became equal to their base "), try_from_word),
                     )
                         .parse(input)
                 }
             };
 
             verify(
-                separated_list1(tag(" "), f).map(|players| {
+                sentence_list(f).map(|players| {
                     let (_, changing_attribute, value_attribute) =
-                        players.first().expect("separated_list1 is never empty");
+                        players.first().expect("sentence_list is never empty");
                     (*changing_attribute, *value_attribute, players)
                 }),
                 |(changing_attribute, value_attribute, players)| {
@@ -498,7 +739,7 @@ fn multiple_attribute_equal(event: &FeedEvent) -> impl TeamFeedEventParser<'_> {
     }
 }
 
-fn recompose<'output>(event: &'output FeedEvent) -> impl TeamFeedEventParser<'output> {
+fn recompose<'output>(event: &FeedEvent) -> impl TeamFeedEventParser<'output> + use<'_, 'output> {
     |input: &'output str| {
         if event.timestamp > Timestamp::Season3RecomposeChange.timestamp() {
             (
@@ -535,10 +776,10 @@ fn enchantment_s1a<'output>() -> impl TeamFeedEventParser<'output> {
             |(team_name, item, amount, attribute)| ParsedTeamFeedEventText::Enchantment {
                 team_name,
                 item,
-                amount,
-                attribute,
-                enchant_two: None,
+                article: None,
+                enchants: vec![(amount, attribute)],
                 compensatory: false,
+                also: false,
             },
         )
 }
@@ -551,18 +792,19 @@ fn enchantment_s1b<'output>() -> impl TeamFeedEventParser<'output> {
         ),
         emojiless_item,
         delimited(tag(" gained a +"), u8, tag(" ")),
-        terminated(try_from_word, tag(" bonus.")),
+        terminated(try_from_word, tag(" bonus")),
+        terminated(enchant_also, tag(".")),
     )
-        .map(
-            |(team_name, item, amount, attribute)| ParsedTeamFeedEventText::Enchantment {
+        .map(|(team_name, item, amount, attribute, also)| {
+            ParsedTeamFeedEventText::Enchantment {
                 team_name,
                 item,
-                amount,
-                attribute,
-                enchant_two: None,
+                article: None,
+                enchants: std::iter::once((amount, attribute)).chain(also).collect(),
                 compensatory: false,
-            },
-        )
+                also: also.is_some(),
+            }
+        })
 }
 
 fn enchantment_s2<'output>() -> impl TeamFeedEventParser<'output> {
@@ -572,29 +814,72 @@ fn enchantment_s2<'output>() -> impl TeamFeedEventParser<'output> {
             parse_terminated("'s "),
         ),
         emojiless_item,
-        preceded(
-            (tag(" was enchanted with "), opt(tag("a ")), tag("+")),
-            separated_pair(u8, tag(" "), try_from_word),
-        ),
         delimited(
-            tag(" and +"),
-            separated_pair(u8, tag(" "), try_from_word),
+            tag(" was enchanted with "),
+            (enchant_article, enchant_bonuses, enchant_also),
             tag("."),
         ),
     )
-        .map(|(team_name, item, (amount, attribute), enchant_two)| {
+        .map(|(team_name, item, (article, mut enchants, also))| {
+            enchants.extend(also);
             ParsedTeamFeedEventText::Enchantment {
+                team_name,
+                item,
+                article,
+                enchants,
+                compensatory: false,
+                also: also.is_some(),
+            }
+        })
+}
+
+/// Speculative: no such line has been captured, so "'s ... lost its +N Attribute bonus." is a
+/// guess.
+fn disenchantment<'output>() -> impl TeamFeedEventParser<'output> {
+    (
+        parse_terminated("'s "),
+        emojiless_item,
+        delimited(
+            tag(" lost its +"),
+            separated_pair(u8, tag(" "), try_from_word),
+            tag(" bonus."),
+        ),
+    )
+        .map(|(team_name, item, (amount, attribute))| {
+            ParsedTeamFeedEventText::Disenchantment {
                 team_name,
                 item,
                 amount,
                 attribute,
-                enchant_two: Some(enchant_two),
-                compensatory: false,
             }
         })
 }
 
 fn enchantment_compensatory<'output>() -> impl TeamFeedEventParser<'output> {
+    separated_list1(tag(" "), compensatory_item_enchantment).map(|mut enchantments| {
+        if enchantments.len() == 1 {
+            let ItemEnchantment {
+                player_name,
+                item,
+                article,
+                enchants,
+                also,
+            } = enchantments.remove(0);
+            ParsedTeamFeedEventText::Enchantment {
+                team_name: player_name,
+                item,
+                article,
+                enchants,
+                compensatory: true,
+                also,
+            }
+        } else {
+            ParsedTeamFeedEventText::CompensatoryEnchantments { enchantments }
+        }
+    })
+}
+
+fn compensatory_item_enchantment(input: &str) -> IResult<'_, &str, ItemEnchantment<&str>> {
     (
         preceded(
             tag("The Compensatory Enchantment was a success! "),
@@ -602,36 +887,33 @@ fn enchantment_compensatory<'output>() -> impl TeamFeedEventParser<'output> {
         ),
         emojiless_item,
         alt((
-            (
-                preceded(
-                    (tag(" was enchanted with "), opt(tag("a ")), tag("+")),
-                    separated_pair(u8, tag(" "), try_from_word),
-                ),
-                delimited(
-                    tag(" and +"),
+            delimited(
+                tag(" was enchanted with "),
+                (enchant_article, enchant_bonuses, enchant_also),
+                tag("."),
+            ),
+            delimited(
+                tag(" gained a +"),
+                (
                     separated_pair(u8, tag(" "), try_from_word),
-                    tag("."),
+                    preceded(tag(" bonus"), enchant_also),
                 ),
+                tag("."),
             )
-                .map(|((amount, attribute), second)| (amount, attribute, Some(second))),
-            (delimited(
-                tag(" gained a +"),
-                separated_pair(u8, tag(" "), try_from_word),
-                tag(" bonus."),
-            )
-            .map(|(amount, attribute)| (amount, attribute, None))),
+            .map(|(bonus, also)| (None, vec![bonus], also)),
         )),
     )
-        .map(|(team_name, item, (amount, attribute, enchant_two))| {
-            ParsedTeamFeedEventText::Enchantment {
-                team_name,
+        .map(|(player_name, item, (article, mut enchants, also))| {
+            enchants.extend(also);
+            ItemEnchantment {
+                player_name,
                 item,
-                amount,
-                attribute,
-                enchant_two,
-                compensatory: true,
+                article,
+                enchants,
+                also: also.is_some(),
             }
         })
+        .parse(input)
 }
 
 fn take_the_mound<'output>() -> impl TeamFeedEventParser<'output> {
@@ -673,29 +955,26 @@ fn modification<'output>() -> impl TeamFeedEventParser<'output> {
                 .map(ModificationType::new)
                 .parse(input)?;
             let (input, _) = (tag(team_name), tag(" gained the ")).parse(input)?;
-            let (input, modification) = parse_terminated(" Modification.")
-                .map(ModificationType::new)
-                .parse(input)?;
+            let (input, (modification, additional_modifications)) = modification_list(input)?;
             Ok((
                 input,
                 ParsedTeamFeedEventText::Modification {
                     team_name,
                     modification,
+                    additional_modifications,
                     lost_modification: Some(lost_modification),
                 },
             ))
         } else {
-            let (input, (team_name, modification)) = (
-                parse_terminated(" gained the "),
-                parse_terminated(" Modification.").map(ModificationType::new),
-            )
-                .parse(input)?;
+            let (input, (team_name, (modification, additional_modifications))) =
+                (parse_terminated(" gained the "), modification_list).parse(input)?;
 
             Ok((
                 input,
                 ParsedTeamFeedEventText::Modification {
                     team_name,
                     modification,
+                    additional_modifications,
                     lost_modification: None,
                 },
             ))
@@ -753,3 +1032,1258 @@ fn lost_lineal_belt(input: &str) -> IResult<'_, &str, ParsedTeamFeedEventText<&s
         },
     ))
 }
+
+#[cfg(test)]
+mod test {
+    use nom::Parser;
+    use nom_language::error::VerboseErrorKind;
+
+    use crate::{
+        enums::{
+            Attribute, Currency, FeedEventType, ItemName, ItemPrefix, ItemSuffix, ModificationType,
+            RosterSlot,
+        },
+        feed_event::{
+            AttributeChange, AttributeOperation, DiscardReason, EmojilessItem, FeedEvent,
+            FeedEventParseError, ItemModChange, ItemModification,
+        },
+        parsed_event::{EmojiTeam, ItemAffixes},
+        team_feed::{AttributeTarget, GameAnnotation, ParsedTeamFeedEventText, ScoreSeparator},
+        time::Timestamp,
+        utils::{assert_unparse_eq, feed_event},
+    };
+
+    fn assert_round_trip(event: &FeedEvent) {
+        let parsed = super::parse_team_feed_event(event);
+        assert!(
+            !matches!(parsed, ParsedTeamFeedEventText::ParseError { .. }),
+            "{parsed:?}"
+        );
+        assert_unparse_eq(&event.text, &parsed.unparse(event));
+    }
+
+    #[test]
+    fn game_result_doubled_space() {
+        let text = "🦆 Ducksburg  Mallards vs. 🐝 Beehive Buzzers - FINAL 3-7";
+        let (_, parsed) = super::game_result().parse(text).unwrap();
+
+        assert_eq!(
+            parsed,
+            ParsedTeamFeedEventText::GameResult {
+                away_team: EmojiTeam {
+                    emoji: "🦆",
+                    name: "Ducksburg  Mallards"
+                },
+                home_team: EmojiTeam {
+                    emoji: "🐝",
+                    name: "Beehive Buzzers"
+                },
+                away_score: 3,
+                home_score: 7,
+                annotation: None,
+                score_separator: None,
+            }
+        );
+    }
+
+    #[test]
+    fn attribute_change_targets() {
+        let event = feed_event(4, 40, "augment", "Yumi Pratt gained +5 Awareness.");
+        assert!(matches!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::AttributeChanges { .. }
+        ));
+        assert_round_trip(&event);
+
+        let event = feed_event(4, 40, "augment", "All players gained +5 Awareness.");
+        assert_eq!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::TeamAttributeChange {
+                target: AttributeTarget::AllPlayers,
+                amount: 5,
+                attribute: Attribute::Awareness,
+            }
+        );
+        assert_round_trip(&event);
+
+        // The wording here is a guess (see `AttributeTarget::Lineup`), so this only checks that
+        // the guess is wired up. Add round trips once real lines are captured.
+        let event = feed_event(4, 40, "augment", "All batters gained +5 Awareness.");
+        assert!(matches!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::TeamAttributeChange {
+                target: AttributeTarget::Lineup,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn roster_moves() {
+        assert_round_trip(&feed_event(
+            6,
+            40,
+            "roster",
+            "🎻 Paloma Moon was moved to the Bench.",
+        ));
+
+        // The wording here is a guess (see `roster_move`), so this only checks that the guess is
+        // wired up. Add round trips once real lines are captured.
+        let event = feed_event(
+            6,
+            40,
+            "roster",
+            "🎻 Paloma Moon was demoted from the Rotation to the Bench.",
+        );
+        assert_eq!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::RosterMove {
+                team_emoji: "🎻",
+                player_name: "Paloma Moon",
+                promoted: false,
+                from: RosterSlot::Rotation,
+                to: RosterSlot::Bench,
+            }
+        );
+    }
+
+    #[test]
+    fn game_result_annotations() {
+        let event = feed_event(4, 12, "game", "🦀 Crabs vs. 🐝 Bees - FINAL 3-7");
+        assert!(matches!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::GameResult {
+                annotation: None,
+                ..
+            }
+        ));
+        assert_round_trip(&event);
+
+        // The wording here is a guess (see `game_annotation`), so this only checks that the guess
+        // is wired up. Add round trips once real lines are captured.
+        for (text, expected) in [
+            (
+                "🦀 Crabs vs. 🐝 Bees - FINAL 2-4 (10 innings)",
+                Some(GameAnnotation::ExtraInnings(10)),
+            ),
+            (
+                "🦀 Crabs vs. 🐝 Bees - FINAL 0-6 (forfeit)",
+                Some(GameAnnotation::Forfeit),
+            ),
+        ] {
+            let event = feed_event(4, 12, "game", text);
+            let ParsedTeamFeedEventText::GameResult { annotation, .. } =
+                super::parse_team_feed_event(&event)
+            else {
+                panic!("{text} should be a game result");
+            };
+            assert_eq!(annotation, expected);
+        }
+    }
+
+    #[test]
+    fn game_result_score_separators() {
+        let event = feed_event(4, 12, "game", "🦀 Crabs vs. 🐝 Bees - FINAL 2-4");
+        assert!(matches!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::GameResult {
+                score_separator: None,
+                ..
+            }
+        ));
+        assert_round_trip(&event);
+
+        // The separators here are guesses (see `score_separator`), so this only checks that the
+        // guesses are wired up. Add round trips once real lines are captured.
+        for (text, expected) in [
+            (
+                "🦀 Crabs vs. 🐝 Bees - FINAL 2 - 4",
+                Some(ScoreSeparator::SpacedHyphen),
+            ),
+            (
+                "🦀 Crabs vs. 🐝 Bees - FINAL 2–4",
+                Some(ScoreSeparator::EnDash),
+            ),
+            (
+                "🦀 Crabs vs. 🐝 Bees - FINAL 2—4 (11 innings)",
+                Some(ScoreSeparator::EmDash),
+            ),
+        ] {
+            let event = feed_event(4, 12, "game", text);
+            let ParsedTeamFeedEventText::GameResult {
+                score_separator,
+                away_score: 2,
+                home_score: 4,
+                ..
+            } = super::parse_team_feed_event(&event)
+            else {
+                panic!("{text} should be a 2-4 game result");
+            };
+            assert_eq!(score_separator, expected);
+        }
+    }
+
+    #[test]
+    fn max_text_len() {
+        use crate::feed_event::ParseOptions;
+
+        let event = feed_event(4, 12, "game", "🦀 Crabs vs. 🐝 Bees - FINAL 3-7");
+        let len = event.text.len();
+
+        let at_limit = ParseOptions { max_text_len: len };
+        assert!(matches!(
+            super::parse_team_feed_event_with(&event, &at_limit),
+            ParsedTeamFeedEventText::GameResult { .. }
+        ));
+
+        let under_limit = ParseOptions {
+            max_text_len: len - 1,
+        };
+        assert_eq!(
+            super::parse_team_feed_event_with(&event, &under_limit),
+            ParsedTeamFeedEventText::ParseError {
+                error: FeedEventParseError::TextTooLong { len },
+                text: event.text.as_str(),
+            }
+        );
+
+        // The default limit is generous, but not unlimited
+        let huge = feed_event(4, 12, "game", &"🦀 Crabs ".repeat(100_000));
+        assert!(matches!(
+            super::parse_team_feed_event(&huge),
+            ParsedTeamFeedEventText::ParseError {
+                error: FeedEventParseError::TextTooLong { .. },
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn game_result_margin() {
+        let event = feed_event(4, 12, "game", "🦀 Crabs vs. 🐝 Bees - FINAL 3-7");
+        assert_eq!(super::parse_team_feed_event(&event).margin(), Some(4));
+
+        let event = feed_event(4, 12, "game", "🦀 Crabs vs. 🐝 Bees - FINAL 9-2");
+        assert_eq!(super::parse_team_feed_event(&event).margin(), Some(7));
+
+        let event = feed_event(4, 12, "season", "Season 4 has begun.");
+        assert_eq!(super::parse_team_feed_event(&event).margin(), None);
+    }
+
+    #[test]
+    fn game_result_vs_inside_name() {
+        let text = "🦆 Canvs Painters vs. 🐝 The Rivals vs. Everyone - FINAL 10-2";
+        let (_, parsed) = super::game_result().parse(text).unwrap();
+
+        assert_eq!(
+            parsed,
+            ParsedTeamFeedEventText::GameResult {
+                away_team: EmojiTeam {
+                    emoji: "🦆",
+                    name: "Canvs Painters"
+                },
+                home_team: EmojiTeam {
+                    emoji: "🐝",
+                    name: "The Rivals vs. Everyone"
+                },
+                away_score: 10,
+                home_score: 2,
+                annotation: None,
+                score_separator: None,
+            }
+        );
+    }
+
+    #[test]
+    fn game_result_vs_inside_away_name() {
+        // The home side has to start with an emoji, so the split skips the first " vs. "
+        let event = feed_event(
+            4,
+            12,
+            "game",
+            "🦆 Us vs. Them vs. 🐝 Beehive Buzzers - FINAL 1-2",
+        );
+        let ParsedTeamFeedEventText::GameResult {
+            away_team,
+            home_team,
+            ..
+        } = super::parse_team_feed_event(&event)
+        else {
+            panic!("expected a game result");
+        };
+        assert_eq!(away_team.name, "Us vs. Them");
+        assert_eq!(home_team.name, "Beehive Buzzers");
+        assert_round_trip(&event);
+    }
+
+    #[test]
+    fn deliveries_with_discard() {
+        for text in [
+            "Yumi Pratt received a 🧢 Cap Delivery. They discarded their 🧢 Cap.",
+            "Yumi Pratt received a 👕 T-Shirt of the Cat Shipment. They discarded their 👕 T-Shirt.",
+            "Yumi Pratt received a 🧢 Artistic Gloves Cap Special Delivery. They discarded their 🧢 Cap.",
+        ] {
+            assert_round_trip(&feed_event(2, 40, "game", text));
+        }
+    }
+
+    #[test]
+    fn delivery_discard_reasons() {
+        for text in [
+            "Yumi Pratt receives a 🧢 Cap Delivery.",
+            "Yumi Pratt receives a 🧢 Cap Delivery. They discard their 🧢 Cap.",
+        ] {
+            let event = feed_event(9, 40, "game", text);
+            let ParsedTeamFeedEventText::Delivery { delivery } =
+                super::parse_team_feed_event(&event)
+            else {
+                panic!("expected a delivery from {text:?}");
+            };
+            assert_eq!(delivery.discard_reason, None);
+            assert_round_trip(&event);
+        }
+
+        // The wordings here are guesses (see `discard_reason` in shared), so this only checks
+        // that the guesses are wired up. Add round trips once real lines are captured.
+        for (reason, text) in [
+            (
+                DiscardReason::InventoryFull,
+                "Yumi Pratt receives a 🧢 Cap Delivery. They discard their 🧢 Cap to make room.",
+            ),
+            (
+                DiscardReason::Replaced,
+                "Yumi Pratt receives a 🧢 Cap Delivery. They discard their 🧢 Cap for the better item.",
+            ),
+            (
+                DiscardReason::InventoryFull,
+                "Yumi Pratt receives a 🧢 Cap Delivery. They move their 🧢 Cap to storage to make room.",
+            ),
+        ] {
+            let event = feed_event(9, 40, "game", text);
+            let ParsedTeamFeedEventText::Delivery { delivery } = super::parse_team_feed_event(&event)
+            else {
+                panic!("expected a delivery from {text:?}");
+            };
+            assert_eq!(delivery.discard_reason, Some(reason));
+        }
+    }
+
+    #[test]
+    fn item_names_containing_the_label() {
+        for (text, rare_name) in [
+            (
+                "Yumi Pratt received a 🧢 The Big Cap Delivery Cap Delivery.",
+                "The Big Cap Delivery",
+            ),
+            (
+                "Yumi Pratt received a 🧢 The Big Cap Delivery Cap Delivery. They discarded their 🧢 Cap.",
+                "The Big Cap Delivery",
+            ),
+            (
+                "Yumi Pratt received a 🧢 Cap Shipment Cap Special Delivery.",
+                "Cap Shipment",
+            ),
+        ] {
+            let event = feed_event(2, 40, "game", text);
+            let (ParsedTeamFeedEventText::Delivery { delivery }
+            | ParsedTeamFeedEventText::SpecialDelivery { delivery }) =
+                super::parse_team_feed_event(&event)
+            else {
+                panic!("expected a delivery from {text:?}");
+            };
+            assert_eq!(delivery.item.item, ItemName::Cap);
+            assert_eq!(delivery.item.affixes, ItemAffixes::RareName(rare_name));
+            assert_round_trip(&event);
+        }
+    }
+
+    #[test]
+    fn deliveries_with_storage() {
+        // The storage wording is a guess (see `stored_item` in shared), so this only checks that
+        // the guess is wired up. Add round trips once real lines are captured.
+        for text in [
+            "Yumi Pratt receives a 🧢 Cap Delivery. They move their 🧢 Cap to storage.",
+            "Yumi Pratt receives a 👕 T-Shirt of the Cat Shipment. They move their 👕 T-Shirt to storage.",
+            "Yumi Pratt receives a 🧢 Artistic Gloves Cap Special Delivery. They move their 🧢 Cap to storage.",
+        ] {
+            let event = feed_event(6, 40, "game", text);
+            let parsed = super::parse_team_feed_event(&event);
+            let Some((_, delivery)) = parsed.delivery() else {
+                panic!("expected a delivery from {text:?}");
+            };
+            assert!(delivery.discarded_to_storage);
+        }
+    }
+
+    #[test]
+    fn enchantment_bonus_counts() {
+        for text in [
+            "The Item Enchantment was a success! Yumi Pratt's Sharp Cap gained a +12 Contact bonus.",
+            "The Item Enchantment was a success! Yumi Pratt's Sharp Cap was enchanted with +12 Contact and +5 Aiming.",
+            "The Compensatory Enchantment was a success! Yumi Pratt's Sharp Cap was enchanted with +12 Contact and +5 Aiming and +3 Lift.",
+        ] {
+            assert_round_trip(&feed_event(4, 40, "augment", text));
+        }
+    }
+
+    #[test]
+    fn enchantment_loads_old_shape() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{enums::Attribute, player_feed::ParsedPlayerFeedEventText};
+
+        let team: ParsedTeamFeedEventText<String> = serde_json::from_value(serde_json::json!({
+            "Enchantment": {
+                "team_name": "Yumi Pratt",
+                "item": { "prefix": null, "item": "Cap", "suffix": null },
+                "amount": 12,
+                "attribute": "Contact",
+                "enchant_two": [5, "Aiming"],
+                "compensatory": true,
+            }
+        }))?;
+        let ParsedTeamFeedEventText::Enchantment {
+            enchants,
+            compensatory,
+            ..
+        } = &team
+        else {
+            panic!("expected an enchantment, got {team:?}");
+        };
+        assert_eq!(
+            enchants,
+            &[(12, Attribute::Contact), (5, Attribute::Aiming)]
+        );
+        assert!(compensatory);
+
+        let player: ParsedPlayerFeedEventText<String> =
+            serde_json::from_value(serde_json::json!({
+                "Enchantment": {
+                    "player_name": "Yumi Pratt",
+                    "item": { "prefix": null, "item": "Cap", "suffix": null },
+                    "amount": 12,
+                    "attribute": "Contact",
+                    "enchant_two": null,
+                    "compensatory": false,
+                }
+            }))?;
+        assert!(matches!(
+            player,
+            ParsedPlayerFeedEventText::Enchantment { enchants, .. }
+                if enchants == [(12, Attribute::Contact)]
+        ));
+
+        // The current shape still round trips
+        assert_eq!(
+            serde_json::from_value::<ParsedTeamFeedEventText<String>>(serde_json::to_value(
+                &team
+            )?)?,
+            team
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn enchantment_item_bounds() {
+        for text in [
+            "Yumi Pratt's Eagle-Eyed Cap of the Acrobat was enchanted with +5 to Contact.",
+            "Yumi Pratt's Amplification Orb was enchanted with +5 to Contact.",
+            "Yumi Pratt's Oracle's Amplification Orb of Patience was enchanted with +10 to Patience.",
+        ] {
+            assert_round_trip(&feed_event(1, 40, "augment", text));
+        }
+
+        for text in [
+            "The Item Enchantment was a success! Yumi Pratt's True Gloves of the Cannon gained a +12 Contact bonus.",
+            "The Item Enchantment was a success! Yumi Pratt's Progress Orb of Awareness was enchanted with +12 Contact and +5 Aiming.",
+        ] {
+            assert_round_trip(&feed_event(4, 40, "augment", text));
+        }
+    }
+
+    #[test]
+    fn compensatory_enchantment_items() {
+        let one = "The Compensatory Enchantment was a success! Yumi Pratt's Sharp Cap gained a +12 Contact bonus.";
+        let event = feed_event(4, 40, "augment", one);
+        assert!(matches!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::Enchantment {
+                compensatory: true,
+                ..
+            }
+        ));
+        assert_round_trip(&event);
+
+        let two = format!("{one} The Compensatory Enchantment was a success! Yumi Pratt's Progress Orb was enchanted with +5 Aiming and +3 Lift.");
+        let event = feed_event(4, 40, "augment", &two);
+        let ParsedTeamFeedEventText::CompensatoryEnchantments { enchantments } =
+            super::parse_team_feed_event(&event)
+        else {
+            panic!("expected multiple compensatory enchantments");
+        };
+        assert_eq!(enchantments.len(), 2);
+        assert_eq!(enchantments[1].enchants.len(), 2);
+        assert_round_trip(&event);
+    }
+
+    #[test]
+    fn enchantment_articles() {
+        for (article, text) in [
+            (None, "The Item Enchantment was a success! Yumi Pratt's Sharp Cap was enchanted with +12 Contact and +5 Aiming."),
+            (Some("a"), "The Item Enchantment was a success! Yumi Pratt's Sharp Cap was enchanted with a +12 Contact and +5 Aiming."),
+            (Some("an"), "The Compensatory Enchantment was a success! Yumi Pratt's Sharp Cap was enchanted with an +11 Awareness and +5 Aiming."),
+        ] {
+            let event = feed_event(4, 40, "augment", text);
+            assert!(matches!(
+                super::parse_team_feed_event(&event),
+                ParsedTeamFeedEventText::Enchantment { article: a, .. } if a == article
+            ));
+            assert_round_trip(&event);
+        }
+    }
+
+    #[test]
+    fn multi_item_deliveries() {
+        let single = feed_event(2, 40, "game", "Yumi Pratt received a 🧢 Cap Delivery.");
+        assert_round_trip(&single);
+
+        let double = feed_event(
+            2,
+            40,
+            "game",
+            "Yumi Pratt received a 🧢 Cap and a 👕 T-Shirt of the Cat Delivery.",
+        );
+        let ParsedTeamFeedEventText::Delivery { delivery } = super::parse_team_feed_event(&double)
+        else {
+            panic!("expected a delivery");
+        };
+        assert_eq!(delivery.additional.len(), 1);
+        assert_round_trip(&double);
+    }
+
+    #[test]
+    fn parse_with_source() {
+        let event = feed_event(2, 40, "game", "Yumi Pratt received a 🧢 Cap Delivery.");
+        let (parsed, source) = super::parse_team_feed_event_with_source(&event);
+
+        assert_eq!(source, event.text);
+        assert_unparse_eq(source, &parsed.unparse(&event));
+    }
+
+    #[test]
+    fn recompose_wordings() {
+        // Before the season 3 change, the new player was named as what they were "recomposed using"
+        let event = feed_event(
+            2,
+            40,
+            "augment",
+            "Yumi Pratt was Recomposed using Kelly Vieira.",
+        );
+        assert_eq!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::Recomposed {
+                previous: "Yumi Pratt",
+                new: "Kelly Vieira",
+            }
+        );
+        assert_round_trip(&event);
+
+        let mut event = feed_event(
+            3,
+            40,
+            "augment",
+            "Yumi Pratt was Recomposed into Kelly Vieira.",
+        );
+        event.timestamp = Timestamp::Season3RecomposeChange.timestamp() + chrono::Duration::days(1);
+        assert_eq!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::Recomposed {
+                previous: "Yumi Pratt",
+                new: "Kelly Vieira",
+            }
+        );
+        assert_round_trip(&event);
+
+        // The wording is picked by date, so the other one shouldn't sneak through
+        let event = feed_event(
+            2,
+            40,
+            "augment",
+            "Yumi Pratt was Recomposed into Kelly Vieira.",
+        );
+        assert!(matches!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::ParseError { .. }
+        ));
+    }
+
+    #[test]
+    fn augment_retirement() {
+        let event = feed_event(5, 40, "augment", "Yumi Pratt retired from MMOLB!");
+        assert_eq!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::Retirement {
+                previous: "Yumi Pratt",
+                new: None,
+            }
+        );
+        assert_round_trip(&event);
+
+        let event = feed_event(
+            5,
+            40,
+            "augment",
+            "Yumi Pratt retired from MMOLB! Kelly Vieira was called up to take their place.",
+        );
+        assert_eq!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::Retirement {
+                previous: "Yumi Pratt",
+                new: Some("Kelly Vieira"),
+            }
+        );
+        assert_round_trip(&event);
+    }
+
+    #[test]
+    fn item_modifications() {
+        // The wording here is a guess (see `item_modification` in shared), so this only checks
+        // that the guess is wired up. Add round trips once real lines are captured.
+        let event = feed_event(5, 40, "augment", "Nancy Bright's Cap became Sharp.");
+        assert_eq!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::ItemModification {
+                modification: ItemModification {
+                    player_name: "Nancy Bright",
+                    item: EmojilessItem {
+                        prefix: None,
+                        item: ItemName::Cap,
+                        suffix: None,
+                    },
+                    change: ItemModChange::PrefixAdded(ItemPrefix::Sharp),
+                }
+            }
+        );
+
+        // The old suffix is part of the item, and the new one is the change
+        let event = feed_event(
+            5,
+            40,
+            "augment",
+            "Nancy Bright's Sharp Cap of the Cat is no longer of the Cat.",
+        );
+        assert_eq!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::ItemModification {
+                modification: ItemModification {
+                    player_name: "Nancy Bright",
+                    item: EmojilessItem {
+                        prefix: Some(ItemPrefix::Sharp),
+                        item: ItemName::Cap,
+                        suffix: Some(ItemSuffix::Cat),
+                    },
+                    change: ItemModChange::SuffixRemoved(ItemSuffix::Cat),
+                }
+            }
+        );
+
+        let event = feed_event(
+            5,
+            40,
+            "augment",
+            "Nancy Bright's Pitcher's Sun Hat became of the Acrobat.",
+        );
+        assert!(matches!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::ItemModification {
+                modification: ItemModification {
+                    change: ItemModChange::SuffixAdded(ItemSuffix::Acrobat),
+                    ..
+                }
+            }
+        ));
+    }
+
+    #[test]
+    fn attribute_set() {
+        // The wording here is a guess (see `attribute_set` in shared), so this only checks that
+        // the guess is wired up. Add round trips once real lines are captured.
+        let event = feed_event(4, 40, "augment", "Nancy Bright's Awareness dropped to 40.");
+        assert!(matches!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::AttributeSet { .. }
+        ));
+
+        // Team feeds only have attribute equals mixed in with other changes
+        let event = feed_event(
+            4,
+            40,
+            "augment",
+            "Yumi Pratt gained +5 Contact. Nancy Bright's Awareness became equal to their base Aiming.",
+        );
+        assert!(matches!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::AttributeOperations { .. }
+        ));
+        assert_round_trip(&event);
+    }
+
+    #[test]
+    fn augment_failure_has_context() {
+        let event = feed_event(4, 40, "augment", "Something nobody has seen before.");
+        let Err(nom::Err::Error(error)) = super::augment(&event).parse(&event.text) else {
+            panic!("expected augment to fail");
+        };
+
+        assert!(error
+            .errors
+            .iter()
+            .any(|(_, kind)| *kind == VerboseErrorKind::Context("Augment Feed Event")));
+    }
+
+    #[test]
+    fn season_milestones() {
+        let event = feed_event(5, 0, "season", "Season 5 has begun.");
+        assert_eq!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::SeasonBegan { season: 5 }
+        );
+        assert_round_trip(&event);
+
+        let event = feed_event(5, 241, "season", "The Postseason begins.");
+        assert_eq!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::PostseasonBegan
+        );
+        assert_round_trip(&event);
+    }
+
+    #[test]
+    fn empty_text() {
+        for text in ["", "   "] {
+            let event = feed_event(5, 10, "game", text);
+            let parsed = super::parse_team_feed_event(&event);
+            assert_eq!(
+                parsed,
+                ParsedTeamFeedEventText::ParseError {
+                    error: FeedEventParseError::EmptyText,
+                    text,
+                }
+            );
+            assert_eq!(parsed.unparse(&event), text);
+
+            let parsed = crate::player_feed::parse_player_feed_event(&event);
+            assert!(matches!(
+                parsed,
+                crate::player_feed::ParsedPlayerFeedEventText::ParseError {
+                    error: FeedEventParseError::EmptyText,
+                    ..
+                }
+            ));
+            assert_eq!(parsed.unparse(&event), text);
+        }
+    }
+
+    #[test]
+    fn mixed_attribute_operations() {
+        let text = "Nancy Bright gained +5 Contact. Kelly Vieira lost 3 Aiming. \
+            Yumi Pratt's Cunning became equal to their base Intimidation.";
+        let event = feed_event(2, 30, "augment", text);
+        assert_eq!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::AttributeOperations {
+                operations: vec![
+                    AttributeOperation::Gain(AttributeChange {
+                        player_name: "Nancy Bright",
+                        amount: 5,
+                        attribute: Attribute::Contact,
+                        multiplier: None,
+                    }),
+                    AttributeOperation::Loss(AttributeChange {
+                        player_name: "Kelly Vieira",
+                        amount: 3,
+                        attribute: Attribute::Aiming,
+                        multiplier: None,
+                    }),
+                    AttributeOperation::Equal {
+                        player_name: "Yumi Pratt",
+                        changing_attribute: Attribute::Cunning,
+                        value_attribute: Attribute::Intimidation,
+                        current: false,
+                    },
+                ]
+            }
+        );
+        assert_round_trip(&event);
+
+        // A gain after another kind of sentence mustn't pull that sentence into its name
+        let gain = AttributeOperation::Gain(AttributeChange {
+            player_name: "Kelly Vieira",
+            amount: 1,
+            attribute: Attribute::Aiming,
+            multiplier: None,
+        });
+        let event = feed_event(
+            2,
+            30,
+            "augment",
+            "Yumi Pratt lost 2 Contact. Kelly Vieira gained +1 Aiming.",
+        );
+        assert_eq!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::AttributeOperations {
+                operations: vec![
+                    AttributeOperation::Loss(AttributeChange {
+                        player_name: "Yumi Pratt",
+                        amount: 2,
+                        attribute: Attribute::Contact,
+                        multiplier: None,
+                    }),
+                    gain.clone(),
+                ]
+            }
+        );
+        assert_round_trip(&event);
+
+        let event = feed_event(
+            2,
+            30,
+            "augment",
+            "Yumi Pratt's Contact became equal to their base Aiming. \
+            Kelly Vieira gained +1 Aiming.",
+        );
+        assert_eq!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::AttributeOperations {
+                operations: vec![
+                    AttributeOperation::Equal {
+                        player_name: "Yumi Pratt",
+                        changing_attribute: Attribute::Contact,
+                        value_attribute: Attribute::Aiming,
+                        current: false,
+                    },
+                    gain,
+                ]
+            }
+        );
+        assert_round_trip(&event);
+
+        // Gains on their own are still the plain variant
+        let event = feed_event(2, 30, "augment", "Nancy Bright gained +5 Contact.");
+        assert!(matches!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::AttributeChanges { .. }
+        ));
+    }
+
+    #[test]
+    fn delivery_articles() {
+        for (article, text) in [
+            ("a", "Yumi Pratt receives a 🧢 Sharp Cap Delivery."),
+            ("an", "Yumi Pratt receives an 🧢 Insightful Cap Delivery."),
+            (
+                "an",
+                "Yumi Pratt received an 👕 Eagle-Eyed T-Shirt Shipment.",
+            ),
+        ] {
+            let season = if text.contains("received") { 2 } else { 9 };
+            let event = feed_event(season, 40, "game", text);
+            let parsed = super::parse_team_feed_event(&event);
+            let (_, delivery) = parsed.delivery().expect("should be a delivery");
+            assert_eq!(delivery.article, Some(article));
+            assert_round_trip(&event);
+        }
+    }
+
+    #[test]
+    fn attribute_multipliers() {
+        let text = "Nancy Bright gained +5 Contact.";
+        let event = feed_event(6, 40, "augment", text);
+        let ParsedTeamFeedEventText::AttributeChanges { changes } =
+            super::parse_team_feed_event(&event)
+        else {
+            panic!("expected attribute changes from {text:?}");
+        };
+        assert_eq!(changes[0].multiplier, None);
+        assert_round_trip(&event);
+
+        let parsed = crate::player_feed::parse_player_feed_event(&event);
+        assert_eq!(parsed.unparse(&event), text);
+
+        // The wording here is a guess (see `attribute_multiplier` in shared), so this only checks
+        // that the guess is wired up. Add round trips once real lines are captured.
+        let text = "Nancy Bright gained +5 Contact x2.";
+        let event = feed_event(6, 40, "augment", text);
+        let ParsedTeamFeedEventText::AttributeChanges { changes } =
+            super::parse_team_feed_event(&event)
+        else {
+            panic!("expected attribute changes from {text:?}");
+        };
+        assert_eq!(changes[0].amount, 5);
+        assert_eq!(changes[0].multiplier, Some(2));
+
+        assert!(matches!(
+            crate::player_feed::parse_player_feed_event(&event),
+            crate::player_feed::ParsedPlayerFeedEventText::AttributeChanges {
+                multiplier: Some(2),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn disenchantment() {
+        // The wording here is a guess (see `disenchantment`), so this only checks that the guess
+        // is wired up. Add round trips once real lines are captured.
+        let text = "Yumi Pratt's Sharp Cap lost its +12 Contact bonus.";
+        let event = feed_event(6, 40, "augment", text);
+        assert!(matches!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::Disenchantment {
+                team_name: "Yumi Pratt",
+                amount: 12,
+                attribute: Attribute::Contact,
+                ..
+            }
+        ));
+        assert!(matches!(
+            crate::player_feed::parse_player_feed_event(&event),
+            crate::player_feed::ParsedPlayerFeedEventText::Disenchantment {
+                player_name: "Yumi Pratt",
+                amount: 12,
+                ..
+            }
+        ));
+
+        // Still an enchantment
+        let event = feed_event(
+            6,
+            40,
+            "augment",
+            "The Item Enchantment was a success! Yumi Pratt's Sharp Cap gained a +12 Contact bonus.",
+        );
+        assert!(matches!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::Enchantment { .. }
+        ));
+    }
+
+    #[test]
+    fn injury_and_recovery() {
+        // The wording here is a guess (see `injury` in shared), so this only checks that the
+        // guess is wired up. Add round trips once real lines are captured.
+        for (season, text) in [
+            (4, "Yumi Pratt was injured!"),
+            (6, "Yumi Pratt is injured!"),
+        ] {
+            let event = feed_event(season, 40, "game", text);
+            assert_eq!(
+                super::parse_team_feed_event(&event),
+                ParsedTeamFeedEventText::Injury {
+                    player_name: "Yumi Pratt"
+                }
+            );
+        }
+
+        for (season, text) in [
+            (4, "Yumi Pratt recovered from their injury!"),
+            (6, "Yumi Pratt is fully healed!"),
+        ] {
+            let event = feed_event(season, 40, "game", text);
+            assert_eq!(
+                super::parse_team_feed_event(&event),
+                ParsedTeamFeedEventText::Recovery {
+                    player_name: "Yumi Pratt"
+                }
+            );
+            assert_eq!(
+                crate::player_feed::parse_player_feed_event(&event),
+                crate::player_feed::ParsedPlayerFeedEventText::Recovery {
+                    player_name: "Yumi Pratt"
+                }
+            );
+        }
+
+        // Still a falling star
+        let event = feed_event(
+            6,
+            40,
+            "game",
+            "Yumi Pratt is injured by the extreme force of the impact!",
+        );
+        assert!(matches!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::FallingStarOutcome { .. }
+        ));
+    }
+
+    #[test]
+    fn game_result_over_255() {
+        let event = feed_event(4, 40, "game", "🦀 Crabs vs. 🐝 Bees - FINAL 300-7");
+        let parsed = super::parse_team_feed_event(&event);
+        assert_eq!(parsed.margin(), Some(293));
+        assert!(matches!(
+            parsed,
+            ParsedTeamFeedEventText::GameResult {
+                away_score: 300,
+                home_score: 7,
+                ..
+            }
+        ));
+        assert_round_trip(&event);
+    }
+
+    #[test]
+    fn modifications() {
+        let event = feed_event(5, 40, "augment", "Yumi Pratt gained the ROBO Modification.");
+        assert!(matches!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::Modification {
+                modification: ModificationType::ROBO,
+                additional_modifications,
+                ..
+            } if additional_modifications.is_empty()
+        ));
+        assert_round_trip(&event);
+
+        // A single modification keeps the shape stored events had before lists were parsed
+        let parsed = super::parse_team_feed_event(&event);
+        let json = serde_json::to_value(&parsed).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "Modification": {
+                "team_name": "Yumi Pratt",
+                "lost_modification": null,
+                "modification": "ROBO",
+            }})
+        );
+        assert_eq!(
+            serde_json::from_value::<ParsedTeamFeedEventText<String>>(json).unwrap(),
+            parsed.map_text(&mut |name| name.to_string())
+        );
+
+        let event = feed_event(
+            5,
+            40,
+            "augment",
+            "Yumi Pratt gained the ROBO and Fire Elemental Modifications.",
+        );
+        assert!(matches!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::Modification {
+                modification: ModificationType::ROBO,
+                additional_modifications,
+                ..
+            } if additional_modifications == [ModificationType::FireElemental]
+        ));
+        assert_round_trip(&event);
+
+        for text in [
+            "Yumi Pratt gained the Giant, Fae and Undead Modifications.",
+            "Yumi Pratt lost the Giant Modification. Yumi Pratt gained the Fae and Undead Modifications.",
+        ] {
+            let event = feed_event(5, 40, "augment", text);
+            assert_round_trip(&event);
+            let parsed = crate::player_feed::parse_player_feed_event(&event);
+            assert_eq!(parsed.unparse(&event), text);
+        }
+    }
+
+    #[test]
+    fn payouts() {
+        // The wording here is a guess (see `payout`), so this only checks that the guess is wired
+        // up. Add round trips once real lines are captured.
+        for (text, expected_amount) in [
+            ("Yumi Pratt received a payout of 75 🪙.", 75),
+            ("Yumi Pratt received a payout of 1,250 🪙.", 1250),
+            ("Yumi Pratt received a payout of 1,000,000 🪙.", 1_000_000),
+        ] {
+            let event = feed_event(6, 40, "game", text);
+            assert!(matches!(
+                super::parse_team_feed_event(&event),
+                ParsedTeamFeedEventText::Payout {
+                    recipient: "Yumi Pratt",
+                    amount,
+                    thousands_separators,
+                    currency: Currency::Tokens,
+                } if amount == expected_amount && thousands_separators == (amount > 999)
+            ));
+        }
+
+        // Without the separator it still parses
+        let event = feed_event(6, 40, "game", "Yumi Pratt received a payout of 1250 🪙.");
+        assert!(matches!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::Payout {
+                amount: 1250,
+                thousands_separators: false,
+                ..
+            }
+        ));
+
+        // Commas only ever separate groups of three, with one to three digits in front
+        for text in [
+            "Yumi Pratt received a payout of 1234,567 🪙.",
+            "Yumi Pratt received a payout of 1,2345 🪙.",
+            "Yumi Pratt received a payout of 1,25 🪙.",
+        ] {
+            let event = feed_event(6, 40, "game", text);
+            assert!(
+                !matches!(
+                    super::parse_team_feed_event(&event),
+                    ParsedTeamFeedEventText::Payout { .. }
+                ),
+                "{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn trailing_whitespace() {
+        for text in [
+            "🦀 Crabs vs. 🐝 Bees - FINAL 3-7\n",
+            "🦀 Crabs vs. 🐝 Bees - FINAL 3-7  ",
+            "🦀 Crabs vs. 🐝 Bees - FINAL 3-7 \r\n",
+        ] {
+            let event = feed_event(4, 40, "game", text);
+            let parsed = super::parse_team_feed_event(&event);
+            assert!(
+                matches!(parsed, ParsedTeamFeedEventText::GameResult { .. }),
+                "{parsed:?}"
+            );
+            assert_eq!(parsed.unparse(&event), text.trim_end());
+            assert_eq!(
+                super::parse_team_feed_event_with_source(&event).1,
+                text.trim_end()
+            );
+        }
+
+        let event = feed_event(
+            4,
+            40,
+            "season",
+            "Yumi Pratt lost 2 durability for playing in Season 3.\n",
+        );
+        assert!(matches!(
+            crate::player_feed::parse_player_feed_event(&event),
+            crate::player_feed::ParsedPlayerFeedEventText::SeasonalDurabilityLoss { .. }
+        ));
+    }
+
+    #[test]
+    fn take_the_plate_and_mound_round_trip() {
+        for text in [
+            "Yumi Pratt was sent to the plate. Kelly Vieira was pulled from the lineup.",
+            "Yumi Pratt was moved to the mound. Kelly Vieira was sent to the lineup.",
+        ] {
+            let event = feed_event(5, 40, "augment", text);
+            match super::parse_team_feed_event(&event) {
+                ParsedTeamFeedEventText::TakeThePlate {
+                    from_lineup_team, ..
+                }
+                | ParsedTeamFeedEventText::TakeTheMound {
+                    to_lineup_team: from_lineup_team,
+                    ..
+                } => assert_eq!(from_lineup_team, "Kelly Vieira"),
+                other => panic!("{other:?}"),
+            }
+            assert_round_trip(&event);
+
+            let parsed = crate::player_feed::parse_player_feed_event(&event);
+            assert_eq!(parsed.unparse(&event), text);
+        }
+    }
+
+    #[test]
+    fn enchantment_also_clause() {
+        // The wording here is a guess (see `enchant_also` in shared), so this only checks that the
+        // guess is wired up. Add round trips once real lines are captured.
+        for text in [
+            "The Item Enchantment was a success! Yumi Pratt's Sharp Cap gained a +12 Contact bonus and also gained +3 Lift.",
+            "The Item Enchantment was a success! Yumi Pratt's Sharp Cap was enchanted with +12 Contact and +5 Aiming and also gained +3 Lift.",
+            "The Compensatory Enchantment was a success! Yumi Pratt's Sharp Cap gained a +12 Contact bonus and also gained +3 Lift.",
+        ] {
+            let event = feed_event(4, 40, "augment", text);
+            match super::parse_team_feed_event(&event) {
+                ParsedTeamFeedEventText::Enchantment { enchants, also, .. } => {
+                    assert!(also);
+                    assert_eq!(enchants.last(), Some(&(3, Attribute::Lift)));
+                }
+                other => panic!("{other:?}"),
+            }
+        }
+
+        let text = "The Compensatory Enchantment was a success! Yumi Pratt's Sharp Cap gained a +12 Contact bonus and also gained +3 Lift. The Compensatory Enchantment was a success! Kelly Vieira's Cap gained a +2 Aiming bonus.";
+        let event = feed_event(4, 40, "augment", text);
+        match super::parse_team_feed_event(&event) {
+            ParsedTeamFeedEventText::CompensatoryEnchantments { enchantments } => {
+                assert_eq!(
+                    enchantments.iter().map(|e| e.also).collect::<Vec<_>>(),
+                    [true, false]
+                );
+            }
+            other => panic!("{other:?}"),
+        }
+    }
+
+    #[test]
+    fn all_star_selection() {
+        // The wording here is a guess (see `all_star_selection` in shared), so this only checks
+        // that the guess is wired up. Add round trips once real lines are captured.
+        let event = feed_event(
+            5,
+            120,
+            "season",
+            "Yumi Pratt was selected to represent the Akron Postmasters in the Superstar Game.",
+        );
+        assert_eq!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::AllStarSelection {
+                player: "Yumi Pratt",
+                team: "Akron Postmasters",
+            }
+        );
+
+        let event = feed_event(5, 120, "season", "Yumi Pratt entered the Superstar Game!");
+        assert_eq!(
+            crate::player_feed::parse_player_feed_event(&event),
+            crate::player_feed::ParsedPlayerFeedEventText::EnteredSuperstarGame {
+                player: "Yumi Pratt"
+            }
+        );
+
+        // Anything else about the Superstar Game still falls through to a parse error
+        let event = feed_event(5, 120, "season", "Yumi Pratt was named Superstar Game MVP!");
+        assert!(matches!(
+            super::parse_team_feed_event(&event),
+            ParsedTeamFeedEventText::ParseError {
+                error: FeedEventParseError::FailedParsingText { .. },
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn failure_position_inside_subparser() {
+        // verify_name runs on just the team name, so the error points into that slice rather
+        // than at the rest of the text. The trailing space is trimmed before parsing.
+        let event = feed_event(4, 10, "release", "Released by the Bad, Team. ");
+        let expected = FeedEventParseError::FailedParsingText {
+            event_type: FeedEventType::Release,
+            text: "Released by the Bad, Team.".to_string(),
+            consumed: "Released by the ".len(),
+        };
+
+        let ParsedTeamFeedEventText::ParseError { error, .. } =
+            super::parse_team_feed_event(&event)
+        else {
+            panic!("a comma in the team name should fail to parse");
+        };
+        assert_eq!(error, expected);
+
+        let crate::player_feed::ParsedPlayerFeedEventText::ParseError { error, .. } =
+            crate::player_feed::parse_player_feed_event(&event)
+        else {
+            panic!("a comma in the team name should fail to parse");
+        };
+        assert_eq!(error, expected);
+    }
+}
@@ -9,7 +9,8 @@ use std::{
 use strum::{Display, EnumDiscriminants, EnumString, IntoStaticStr};
 use thiserror::Error;
 
-use crate::enums::{Attribute, FoodName};
+use crate::enums::{Attribute, EquipmentRarity, FoodName};
+use crate::feed_event::EmojilessItem;
 use crate::nom_parsing::shared::{discarded_text, received_text};
 use crate::UnparsingContext;
 use crate::{
@@ -266,8 +267,9 @@ pub enum ParsedEventMessage<S> {
     WeatherDelivery {
         delivery: Delivery<S>,
     },
+    /// One sentence per player, for showers that hit more than one
     FallingStar {
-        player_name: S,
+        players: Vec<S>,
     },
     FallingStarOutcome {
         deflection: Option<S>,
@@ -963,9 +965,13 @@ impl<S: Display> ParsedEventMessage<S> {
             Self::WeatherDelivery { delivery } => {
                 delivery.unparse(context, event_index, "Delivery")
             }
-            Self::FallingStar { player_name } => {
-                format!("<strong>🌠 {player_name} is hit by a Falling Star!</strong>")
-            }
+            Self::FallingStar { players } => players
+                .iter()
+                .map(|player_name| {
+                    format!("<strong>🌠 {player_name} is hit by a Falling Star!</strong>")
+                })
+                .collect::<Vec<_>>()
+                .join(" "),
             Self::FallingStarOutcome {
                 deflection,
                 player_name,
@@ -1154,7 +1160,7 @@ impl<S: Display> ParsedEventMessage<S> {
                 format!("{real_team} were defeated by the {simulacrum_team} and earned {tokens_earnt} 🪙.")
             }
             Self::WeatherSimulacrumOffseason => {
-                format!("The Simulacrum yields no tokens during the Offseason.")
+                "The Simulacrum yields no tokens during the Offseason.".to_string()
             }
         }
     }
@@ -1262,6 +1268,15 @@ impl<S: AsRef<str>> EmojiTeam<S> {
         }
     }
 }
+
+impl<S> EmojiTeam<S> {
+    pub fn map_text<T>(&self, f: &mut impl FnMut(&S) -> T) -> EmojiTeam<T> {
+        EmojiTeam {
+            emoji: f(&self.emoji),
+            name: f(&self.name),
+        }
+    }
+}
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub struct EmojiPlayer<S> {
     pub emoji: S,
@@ -1282,6 +1297,15 @@ impl<S: AsRef<str>> EmojiPlayer<S> {
     }
 }
 
+impl<S> EmojiPlayer<S> {
+    pub fn map_text<T>(&self, f: &mut impl FnMut(&S) -> T) -> EmojiPlayer<T> {
+        EmojiPlayer {
+            emoji: f(&self.emoji),
+            name: f(&self.name),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub struct PlacedPlayer<S> {
     pub name: S,
@@ -1465,6 +1489,18 @@ impl<S: AsRef<str>> ItemAffixes<S> {
     }
 }
 
+impl<S> ItemAffixes<S> {
+    pub fn map_text<T>(&self, f: &mut impl FnMut(&S) -> T) -> ItemAffixes<T> {
+        match self {
+            ItemAffixes::RareName(s) => ItemAffixes::RareName(f(s)),
+            ItemAffixes::PrefixSuffix(prefix, suffix) => {
+                ItemAffixes::PrefixSuffix(prefix.clone(), suffix.clone())
+            }
+            ItemAffixes::None => ItemAffixes::None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Item<S> {
     pub item_emoji: S,
@@ -1482,6 +1518,58 @@ impl<S: AsRef<str>> Item<S> {
     }
 }
 
+impl<S> Item<S> {
+    pub fn map_text<T>(&self, f: &mut impl FnMut(&S) -> T) -> Item<T> {
+        Item {
+            item_emoji: f(&self.item_emoji),
+            item: self.item,
+            affixes: self.affixes.map_text(f),
+        }
+    }
+}
+
+impl<S> Item<S> {
+    /// The item without its emoji. `None` for rare names, items with more than one prefix or
+    /// suffix, and empty affix lists, since `EmojilessItem` can't hold those. An empty
+    /// `PrefixSuffix` would come back from [`EmojilessItem::with_emoji`] as `ItemAffixes::None`.
+    pub fn without_emoji(&self) -> Option<EmojilessItem> {
+        let (prefix, suffix) = match &self.affixes {
+            ItemAffixes::None => (None, None),
+            ItemAffixes::PrefixSuffix(prefix, suffix)
+                if prefix.len() + suffix.len() > 0 && prefix.len() <= 1 && suffix.len() <= 1 =>
+            {
+                (prefix.first().copied(), suffix.first().copied())
+            }
+            ItemAffixes::PrefixSuffix(..) | ItemAffixes::RareName(_) => return None,
+        };
+
+        Some(EmojilessItem {
+            prefix,
+            item: self.item,
+            suffix,
+        })
+    }
+
+    /// Number of prefixes plus suffixes in the item's name. Rare names don't show their
+    /// enchantments, so they count as 0.
+    pub fn enchant_count(&self) -> u8 {
+        match &self.affixes {
+            ItemAffixes::PrefixSuffix(prefix, suffix) => (prefix.len() + suffix.len()) as u8,
+            ItemAffixes::None | ItemAffixes::RareName(_) => 0,
+        }
+    }
+
+    /// Rarity as implied by the name: no affixes is Normal, any prefix or suffix is Magic, and a
+    /// rare name is Rare. The affixes are what was actually parsed; this is derived from them.
+    pub fn rarity(&self) -> EquipmentRarity {
+        match &self.affixes {
+            ItemAffixes::None => EquipmentRarity::Normal,
+            ItemAffixes::PrefixSuffix(..) => EquipmentRarity::Magic,
+            ItemAffixes::RareName(_) => EquipmentRarity::Rare,
+        }
+    }
+}
+
 impl<S: Display> Display for Item<S> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let Item {
@@ -2223,6 +2311,22 @@ impl<S: AsRef<str>> ItemEquip<S> {
     }
 }
 
+impl<S> ItemEquip<S> {
+    pub fn map_text<T>(&self, f: &mut impl FnMut(&S) -> T) -> ItemEquip<T> {
+        match self {
+            ItemEquip::None => ItemEquip::None,
+            ItemEquip::Discarded => ItemEquip::Discarded,
+            ItemEquip::Equipped {
+                player_name,
+                discarded_item,
+            } => ItemEquip::Equipped {
+                player_name: f(player_name),
+                discarded_item: discarded_item.as_ref().map(|i| i.map_text(f)),
+            },
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ItemPrize<S> {
     pub item: Item<S>,
@@ -2289,6 +2393,23 @@ impl<S: AsRef<str>> Prize<S> {
     }
 }
 
+impl<S> Prize<S> {
+    pub fn map_text<T>(&self, f: &mut impl FnMut(&S) -> T) -> Prize<T> {
+        match self {
+            Prize::Items(items) => Prize::Items(
+                items
+                    .iter()
+                    .map(|prize| ItemPrize {
+                        item: prize.item.map_text(f),
+                        equip: prize.equip.map_text(f),
+                    })
+                    .collect(),
+            ),
+            Prize::Tokens(t) => Prize::Tokens(*t),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct DoorPrize<S> {
     pub player: S,
@@ -2615,7 +2736,41 @@ mod test {
 
     use serde::Deserialize;
 
-    use crate::{parsed_event::Cheer, process_game, utils::no_tracing_errs, Game};
+    use crate::{
+        enums::{EquipmentRarity, ItemName, ItemPrefix, ItemSuffix},
+        parsed_event::{Cheer, Item, ItemAffixes},
+        process_game,
+        utils::no_tracing_errs,
+        Game,
+    };
+
+    #[test]
+    fn item_enchant_count_and_rarity() {
+        let item = |affixes| Item {
+            item_emoji: "🧢",
+            item: ItemName::Cap,
+            affixes,
+        };
+
+        let plain = item(ItemAffixes::None);
+        assert_eq!(plain.enchant_count(), 0);
+        assert_eq!(plain.rarity(), EquipmentRarity::Normal);
+
+        let prefixed = item(ItemAffixes::PrefixSuffix(vec![ItemPrefix::Sharp], vec![]));
+        assert_eq!(prefixed.enchant_count(), 1);
+        assert_eq!(prefixed.rarity(), EquipmentRarity::Magic);
+
+        let both = item(ItemAffixes::PrefixSuffix(
+            vec![ItemPrefix::Sharp],
+            vec![ItemSuffix::Cat],
+        ));
+        assert_eq!(both.enchant_count(), 2);
+        assert_eq!(both.rarity(), EquipmentRarity::Magic);
+
+        let rare = item(ItemAffixes::RareName("Artistic Gloves"));
+        assert_eq!(rare.enchant_count(), 0);
+        assert_eq!(rare.rarity(), EquipmentRarity::Rare);
+    }
 
     //https://freecashe.ws/api/chron/v0/entities?kind=game&id=6851bb34f419fdc04f9d0ed5,685b744530d8d1ac659c30de,68611cb61e65f5fb52cb618f,68611cb61e65f5fb52cb61d6,68799d0621c82ae41451ca4f,68782f7d206bc4d2a2003b05,6879f14e21c82ae41451e785,6893c2899361d52a6890a9f0
     #[test]
@@ -1,21 +1,83 @@
-use std::fmt::Display;
+use std::{
+    fmt::{Debug, Display},
+    sync::Arc,
+};
 
+use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
+use strum::IntoStaticStr;
 
 use crate::feed_event::PlayerGreaterAugment;
-pub use crate::nom_parsing::parse_player_feed_event::parse_player_feed_event;
-use crate::nom_parsing::shared::{FeedEventDoorPrize, FeedEventParty, Grow, PositionSwap};
+pub use crate::nom_parsing::parse_player_feed_event::{
+    parse_player_feed_event, parse_player_feed_event_with, parse_player_feed_event_with_source,
+};
+use crate::nom_parsing::shared::{
+    unparse_modification_list, FeedEventDoorPrize, FeedEventParty, Grow, PositionSwap,
+};
 use crate::team_feed::PurifiedOutcome;
 use crate::{
     enums::{Attribute, FeedEventType, ModificationType},
     feed_event::{
-        EmojilessItem, FeedDelivery, FeedEvent, FeedEventParseError, FeedFallingStarOutcome,
+        deserialize_enchantment, parse_ndjson, unparse_enchantment, AttributeChange,
+        AttributeEqualPhrasing, AttributeSet, DeliveryKind, EmojilessItem, FeedDelivery, FeedEvent,
+        FeedEventParseError, FeedFallingStarOutcome, FeedNdjsonError, ItemEnchantment,
+        ItemModification, ParserRegistry, StringInterner,
     },
     time::{Breakpoints, Timestamp},
     utils::extra_fields_deserialize,
 };
 
+/// Like [`parse_player_feed_event`], but the result doesn't borrow from the event.
+pub fn parse_player_feed_event_owned(event: &FeedEvent) -> ParsedPlayerFeedEventText<String> {
+    parse_player_feed_event(event).map_text(&mut |s| s.to_string())
+}
+
+/// See [`crate::team_feed::parse_team_feed_events_interned`]
+pub fn parse_player_feed_events_interned(
+    events: &[FeedEvent],
+) -> (Vec<ParsedPlayerFeedEventText<Arc<str>>>, StringInterner) {
+    let mut interner = StringInterner::new();
+    let parsed = events
+        .iter()
+        .map(|event| parse_player_feed_event(event).intern(&mut interner))
+        .collect();
+    (parsed, interner)
+}
+
+/// See [`crate::team_feed::parse_team_feed_event_in`]
+#[cfg(feature = "bumpalo")]
+pub fn parse_player_feed_event_in<'a>(
+    event: &FeedEvent,
+    arena: &'a bumpalo::Bump,
+) -> ParsedPlayerFeedEventText<&'a str> {
+    crate::nom_parsing::parse_player_feed_event::parse_player_feed_event_text(
+        event,
+        arena.alloc_str(&event.text),
+        &crate::feed_event::ParseOptions::default(),
+    )
+}
+
+/// Like [`parse_player_feed_event_owned`], but tries the parsers in `registry` for the event's type first.
+pub fn parse_player_feed_event_with_registry(
+    event: &FeedEvent,
+    registry: &ParserRegistry<ParsedPlayerFeedEventText<String>>,
+) -> ParsedPlayerFeedEventText<String> {
+    event
+        .event_type
+        .as_ref()
+        .ok()
+        .and_then(|event_type| registry.parse(*event_type, &event.text))
+        .unwrap_or_else(|| parse_player_feed_event_owned(event))
+}
+
+/// Parses a newline-delimited dump of player feed events one line at a time.
+pub fn parse_player_feed_events_ndjson<R: std::io::BufRead>(
+    reader: R,
+) -> impl Iterator<Item = Result<ParsedPlayerFeedEventText<String>, FeedNdjsonError>> {
+    parse_ndjson(reader, parse_player_feed_event_owned)
+}
+
 #[serde_as]
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct PlayerFeed {
@@ -25,7 +87,7 @@ pub struct PlayerFeed {
     pub extra_fields: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, IntoStaticStr)]
 pub enum ParsedPlayerFeedEventText<S> {
     ParseError {
         error: FeedEventParseError,
@@ -47,11 +109,22 @@ pub enum ParsedPlayerFeedEventText<S> {
         player_name: S,
         amount: i16,
         attribute: Attribute,
+        /// The "x2" some bonuses have after them. Speculative, see
+        /// [`crate::feed_event::AttributeChange::multiplier`].
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        multiplier: Option<u8>,
     },
     AttributeEquals {
         player_name: S,
         changing_attribute: Attribute,
+        /// Whose attribute it was set to, when it isn't the same player's
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        value_player: Option<S>,
         value_attribute: Attribute,
+        /// Which wording the text used. Falls back to the one in use at the time of the event
+        /// when missing.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        phrasing: Option<AttributeEqualPhrasing>,
     },
 
     TakeTheMound {
@@ -67,13 +140,41 @@ pub enum ParsedPlayerFeedEventText<S> {
         player_two: S,
     },
 
+    /// Also loads the `S1Enchantment` and `S2Enchantment` shapes that [`ParsedFeedEventText`]
+    /// serialized, so events stored before the split still load.
+    ///
+    /// [`ParsedFeedEventText`]: crate::feed_event::ParsedFeedEventText
+    #[serde(
+        alias = "S1Enchantment",
+        alias = "S2Enchantment",
+        deserialize_with = "deserialize_enchantment"
+    )]
     Enchantment {
+        player_name: S,
+        item: EmojilessItem,
+        /// The "a" or "an" before a list of bonuses, if there was one
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        article: Option<S>,
+        /// Amount and attribute of each bonus. Always at least one.
+        enchants: Vec<(u8, Attribute)>,
+        compensatory: bool,
+        /// The last of `enchants` came from a trailing "and also gained +N Attribute" clause.
+        /// Speculative, see [`crate::team_feed::ParsedTeamFeedEventText::Enchantment`].
+        #[serde(default)]
+        also: bool,
+    },
+    /// A compensatory enchantment that hit more than one item, one sentence per item. A single
+    /// item is parsed as a compensatory `Enchantment`.
+    CompensatoryEnchantments {
+        enchantments: Vec<ItemEnchantment<S>>,
+    },
+    /// An item losing one of its bonuses. Speculative, see
+    /// [`crate::team_feed::ParsedTeamFeedEventText::Disenchantment`].
+    Disenchantment {
         player_name: S,
         item: EmojilessItem,
         amount: u8,
         attribute: Attribute,
-        enchant_two: Option<(u8, Attribute)>,
-        compensatory: bool,
     },
 
     FallingStarOutcome {
@@ -95,6 +196,9 @@ pub enum ParsedPlayerFeedEventText<S> {
         player_name: S,
         lost_modification: Option<ModificationType>,
         modification: ModificationType,
+        /// Any modifications after the first, for events that grant more than one
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        additional_modifications: Vec<ModificationType>,
     },
     SeasonalDurabilityLoss {
         player_name: S,
@@ -102,9 +206,30 @@ pub enum ParsedPlayerFeedEventText<S> {
         durability_lost: Option<u32>,
         season: u32,
     },
+    /// A player picked to play in the Superstar Game.
+    ///
+    /// Speculative: no real line for this has been captured, so the wording it's parsed from is
+    /// a guess and may never match anything.
+    AllStarSelection {
+        player: S,
+        team: S,
+    },
+    /// A player selected for the Superstar Game taking the field in it. Speculative, like
+    /// `AllStarSelection`.
+    EnteredSuperstarGame {
+        player: S,
+    },
     CorruptedByWither {
         player_name: S,
     },
+    /// Speculative, see [`crate::team_feed::ParsedTeamFeedEventText::Injury`]
+    Injury {
+        player_name: S,
+    },
+    /// Speculative, see [`crate::team_feed::ParsedTeamFeedEventText::Injury`]
+    Recovery {
+        player_name: S,
+    },
     Purified {
         player_name: S,
         outcome: PurifiedOutcome,
@@ -122,6 +247,15 @@ pub enum ParsedPlayerFeedEventText<S> {
     PlayerGrow {
         grow: Grow<S>,
     },
+    /// An attribute set to a number instead of to another attribute. Speculative, see
+    /// [`AttributeSet`].
+    AttributeSet {
+        set: AttributeSet<S>,
+    },
+    /// An item gaining or losing a prefix or suffix. Speculative, see [`ItemModification`].
+    ItemModification {
+        modification: ItemModification<S>,
+    },
     GreaterAugment {
         player_name: S,
         greater_augment: PlayerGreaterAugment,
@@ -148,7 +282,479 @@ pub enum ParsedPlayerFeedEventText<S> {
     },
 }
 
+impl<S> ParsedPlayerFeedEventText<S> {
+    /// See [`crate::team_feed::ParsedTeamFeedEventText::delivery`]
+    pub fn delivery(&self) -> Option<(DeliveryKind, &FeedDelivery<S>)> {
+        match self {
+            ParsedPlayerFeedEventText::Delivery { delivery } => {
+                Some((DeliveryKind::Delivery, delivery))
+            }
+            ParsedPlayerFeedEventText::Shipment { delivery } => {
+                Some((DeliveryKind::Shipment, delivery))
+            }
+            ParsedPlayerFeedEventText::SpecialDelivery { delivery } => {
+                Some((DeliveryKind::SpecialDelivery, delivery))
+            }
+            _ => None,
+        }
+    }
+
+    /// The change in an [`ParsedPlayerFeedEventText::AttributeChanges`] event, as an
+    /// [`AttributeChange`] that borrows the player's name. None for any other event.
+    pub fn attribute_change(&self) -> Option<AttributeChange<&S>> {
+        match self {
+            ParsedPlayerFeedEventText::AttributeChanges {
+                player_name,
+                amount,
+                attribute,
+                multiplier,
+            } => Some(AttributeChange {
+                player_name,
+                amount: *amount,
+                attribute: *attribute,
+                multiplier: *multiplier,
+            }),
+            _ => None,
+        }
+    }
+
+    /// See [`crate::team_feed::ParsedTeamFeedEventText::is_known`]
+    pub fn is_known(&self) -> bool {
+        !matches!(self, ParsedPlayerFeedEventText::ParseError { .. })
+    }
+
+    /// See [`crate::team_feed::ParsedTeamFeedEventText::feed_event_type`]
+    pub fn feed_event_type(&self) -> Option<FeedEventType> {
+        match self {
+            ParsedPlayerFeedEventText::ParseError {
+                error: FeedEventParseError::FailedParsingText { event_type, .. },
+                ..
+            } => Some(*event_type),
+            ParsedPlayerFeedEventText::ParseError { .. }
+            | ParsedPlayerFeedEventText::Retirement { .. } => None,
+            ParsedPlayerFeedEventText::Delivery { .. }
+            | ParsedPlayerFeedEventText::Shipment { .. }
+            | ParsedPlayerFeedEventText::SpecialDelivery { .. }
+            | ParsedPlayerFeedEventText::DoorPrize { .. }
+            | ParsedPlayerFeedEventText::FallingStarOutcome { .. }
+            | ParsedPlayerFeedEventText::CorruptedByWither { .. }
+            | ParsedPlayerFeedEventText::Injury { .. }
+            | ParsedPlayerFeedEventText::Recovery { .. }
+            | ParsedPlayerFeedEventText::Party { .. }
+            | ParsedPlayerFeedEventText::PlayerContained { .. } => Some(FeedEventType::Game),
+            ParsedPlayerFeedEventText::AttributeChanges { .. }
+            | ParsedPlayerFeedEventText::AttributeEquals { .. }
+            | ParsedPlayerFeedEventText::TakeTheMound { .. }
+            | ParsedPlayerFeedEventText::TakeThePlate { .. }
+            | ParsedPlayerFeedEventText::SwapPlaces { .. }
+            | ParsedPlayerFeedEventText::Enchantment { .. }
+            | ParsedPlayerFeedEventText::CompensatoryEnchantments { .. }
+            | ParsedPlayerFeedEventText::Disenchantment { .. }
+            | ParsedPlayerFeedEventText::Recomposed { .. }
+            | ParsedPlayerFeedEventText::Modification { .. }
+            | ParsedPlayerFeedEventText::Purified { .. }
+            | ParsedPlayerFeedEventText::PlayerPositionsSwapped { .. }
+            | ParsedPlayerFeedEventText::PlayerGrow { .. }
+            | ParsedPlayerFeedEventText::AttributeSet { .. }
+            | ParsedPlayerFeedEventText::ItemModification { .. } => Some(FeedEventType::Augment),
+            ParsedPlayerFeedEventText::Released { .. } => Some(FeedEventType::Release),
+            ParsedPlayerFeedEventText::SeasonalDurabilityLoss { .. }
+            | ParsedPlayerFeedEventText::AllStarSelection { .. }
+            | ParsedPlayerFeedEventText::EnteredSuperstarGame { .. } => Some(FeedEventType::Season),
+            ParsedPlayerFeedEventText::GreaterAugment { .. }
+            | ParsedPlayerFeedEventText::RetractedGreaterAugment { .. }
+            | ParsedPlayerFeedEventText::RetroactiveGreaterAugment { .. } => {
+                Some(FeedEventType::Election)
+            }
+            ParsedPlayerFeedEventText::PlayerRelegated { .. }
+            | ParsedPlayerFeedEventText::PlayerMoved { .. } => Some(FeedEventType::Roster),
+        }
+    }
+
+    /// See [`crate::team_feed::ParsedTeamFeedEventText::map_text`]
+    pub fn map_text<T>(&self, f: &mut impl FnMut(&S) -> T) -> ParsedPlayerFeedEventText<T> {
+        match self {
+            ParsedPlayerFeedEventText::ParseError { error, text } => {
+                ParsedPlayerFeedEventText::ParseError {
+                    error: error.clone(),
+                    text: f(text),
+                }
+            }
+            ParsedPlayerFeedEventText::Delivery { delivery } => {
+                ParsedPlayerFeedEventText::Delivery {
+                    delivery: delivery.map_text(f),
+                }
+            }
+            ParsedPlayerFeedEventText::Shipment { delivery } => {
+                ParsedPlayerFeedEventText::Shipment {
+                    delivery: delivery.map_text(f),
+                }
+            }
+            ParsedPlayerFeedEventText::SpecialDelivery { delivery } => {
+                ParsedPlayerFeedEventText::SpecialDelivery {
+                    delivery: delivery.map_text(f),
+                }
+            }
+            ParsedPlayerFeedEventText::DoorPrize { prize } => {
+                ParsedPlayerFeedEventText::DoorPrize {
+                    prize: prize.map_text(f),
+                }
+            }
+            ParsedPlayerFeedEventText::AttributeChanges {
+                player_name,
+                amount,
+                attribute,
+                multiplier,
+            } => ParsedPlayerFeedEventText::AttributeChanges {
+                player_name: f(player_name),
+                amount: *amount,
+                attribute: *attribute,
+                multiplier: *multiplier,
+            },
+            ParsedPlayerFeedEventText::AttributeEquals {
+                player_name,
+                changing_attribute,
+                value_player,
+                value_attribute,
+                phrasing,
+            } => ParsedPlayerFeedEventText::AttributeEquals {
+                player_name: f(player_name),
+                changing_attribute: *changing_attribute,
+                value_player: value_player.as_ref().map(&mut *f),
+                value_attribute: *value_attribute,
+                phrasing: *phrasing,
+            },
+            ParsedPlayerFeedEventText::TakeTheMound {
+                to_mound_player,
+                to_lineup_player,
+            } => ParsedPlayerFeedEventText::TakeTheMound {
+                to_mound_player: f(to_mound_player),
+                to_lineup_player: f(to_lineup_player),
+            },
+            ParsedPlayerFeedEventText::TakeThePlate {
+                to_plate_player,
+                from_lineup_player,
+            } => ParsedPlayerFeedEventText::TakeThePlate {
+                to_plate_player: f(to_plate_player),
+                from_lineup_player: f(from_lineup_player),
+            },
+            ParsedPlayerFeedEventText::SwapPlaces {
+                player_one,
+                player_two,
+            } => ParsedPlayerFeedEventText::SwapPlaces {
+                player_one: f(player_one),
+                player_two: f(player_two),
+            },
+            ParsedPlayerFeedEventText::Enchantment {
+                player_name,
+                item,
+                article,
+                enchants,
+                compensatory,
+                also,
+            } => ParsedPlayerFeedEventText::Enchantment {
+                player_name: f(player_name),
+                item: *item,
+                article: article.as_ref().map(&mut *f),
+                enchants: enchants.clone(),
+                compensatory: *compensatory,
+                also: *also,
+            },
+            ParsedPlayerFeedEventText::CompensatoryEnchantments { enchantments } => {
+                ParsedPlayerFeedEventText::CompensatoryEnchantments {
+                    enchantments: enchantments
+                        .iter()
+                        .map(|enchantment| enchantment.map_text(f))
+                        .collect(),
+                }
+            }
+            ParsedPlayerFeedEventText::Disenchantment {
+                player_name,
+                item,
+                amount,
+                attribute,
+            } => ParsedPlayerFeedEventText::Disenchantment {
+                player_name: f(player_name),
+                item: *item,
+                amount: *amount,
+                attribute: *attribute,
+            },
+            ParsedPlayerFeedEventText::FallingStarOutcome {
+                player_name,
+                outcome,
+            } => ParsedPlayerFeedEventText::FallingStarOutcome {
+                player_name: f(player_name),
+                outcome: *outcome,
+            },
+            ParsedPlayerFeedEventText::Recomposed { previous, new } => {
+                ParsedPlayerFeedEventText::Recomposed {
+                    previous: f(previous),
+                    new: f(new),
+                }
+            }
+            ParsedPlayerFeedEventText::Released { team } => {
+                ParsedPlayerFeedEventText::Released { team: f(team) }
+            }
+            ParsedPlayerFeedEventText::Retirement { previous, new } => {
+                ParsedPlayerFeedEventText::Retirement {
+                    previous: f(previous),
+                    new: new.as_ref().map(&mut *f),
+                }
+            }
+            ParsedPlayerFeedEventText::Modification {
+                player_name,
+                lost_modification,
+                modification,
+                additional_modifications,
+            } => ParsedPlayerFeedEventText::Modification {
+                player_name: f(player_name),
+                lost_modification: lost_modification.clone(),
+                modification: modification.clone(),
+                additional_modifications: additional_modifications.clone(),
+            },
+            ParsedPlayerFeedEventText::SeasonalDurabilityLoss {
+                player_name,
+                durability_lost,
+                season,
+            } => ParsedPlayerFeedEventText::SeasonalDurabilityLoss {
+                player_name: f(player_name),
+                durability_lost: *durability_lost,
+                season: *season,
+            },
+            ParsedPlayerFeedEventText::AllStarSelection { player, team } => {
+                ParsedPlayerFeedEventText::AllStarSelection {
+                    player: f(player),
+                    team: f(team),
+                }
+            }
+            ParsedPlayerFeedEventText::EnteredSuperstarGame { player } => {
+                ParsedPlayerFeedEventText::EnteredSuperstarGame { player: f(player) }
+            }
+            ParsedPlayerFeedEventText::CorruptedByWither { player_name } => {
+                ParsedPlayerFeedEventText::CorruptedByWither {
+                    player_name: f(player_name),
+                }
+            }
+            ParsedPlayerFeedEventText::Injury { player_name } => {
+                ParsedPlayerFeedEventText::Injury {
+                    player_name: f(player_name),
+                }
+            }
+            ParsedPlayerFeedEventText::Recovery { player_name } => {
+                ParsedPlayerFeedEventText::Recovery {
+                    player_name: f(player_name),
+                }
+            }
+            ParsedPlayerFeedEventText::Purified {
+                player_name,
+                outcome,
+            } => ParsedPlayerFeedEventText::Purified {
+                player_name: f(player_name),
+                outcome: outcome.clone(),
+            },
+            ParsedPlayerFeedEventText::Party { party } => ParsedPlayerFeedEventText::Party {
+                party: party.map_text(f),
+            },
+            ParsedPlayerFeedEventText::PlayerContained {
+                contained_player_name,
+                container_player_name,
+            } => ParsedPlayerFeedEventText::PlayerContained {
+                contained_player_name: f(contained_player_name),
+                container_player_name: f(container_player_name),
+            },
+            ParsedPlayerFeedEventText::PlayerPositionsSwapped { swap } => {
+                ParsedPlayerFeedEventText::PlayerPositionsSwapped {
+                    swap: swap.map_text(f),
+                }
+            }
+            ParsedPlayerFeedEventText::PlayerGrow { grow } => {
+                ParsedPlayerFeedEventText::PlayerGrow {
+                    grow: grow.map_text(f),
+                }
+            }
+            ParsedPlayerFeedEventText::AttributeSet { set } => {
+                ParsedPlayerFeedEventText::AttributeSet {
+                    set: set.map_text(f),
+                }
+            }
+            ParsedPlayerFeedEventText::ItemModification { modification } => {
+                ParsedPlayerFeedEventText::ItemModification {
+                    modification: modification.map_text(f),
+                }
+            }
+            ParsedPlayerFeedEventText::GreaterAugment {
+                player_name,
+                greater_augment,
+            } => ParsedPlayerFeedEventText::GreaterAugment {
+                player_name: f(player_name),
+                greater_augment: greater_augment.clone(),
+            },
+            ParsedPlayerFeedEventText::RetractedGreaterAugment {
+                player_name,
+                greater_augment,
+            } => ParsedPlayerFeedEventText::RetractedGreaterAugment {
+                player_name: f(player_name),
+                greater_augment: greater_augment.clone(),
+            },
+            ParsedPlayerFeedEventText::RetroactiveGreaterAugment {
+                player_name,
+                greater_augment,
+            } => ParsedPlayerFeedEventText::RetroactiveGreaterAugment {
+                player_name: f(player_name),
+                greater_augment: greater_augment.clone(),
+            },
+            ParsedPlayerFeedEventText::PlayerRelegated { player_name } => {
+                ParsedPlayerFeedEventText::PlayerRelegated {
+                    player_name: f(player_name),
+                }
+            }
+            ParsedPlayerFeedEventText::PlayerMoved {
+                team_emoji,
+                player_name,
+            } => ParsedPlayerFeedEventText::PlayerMoved {
+                team_emoji: f(team_emoji),
+                player_name: f(player_name),
+            },
+        }
+    }
+}
+
+impl<S: AsRef<str>> ParsedPlayerFeedEventText<S> {
+    /// See [`crate::team_feed::ParsedTeamFeedEventText::intern`]
+    pub fn intern(&self, interner: &mut StringInterner) -> ParsedPlayerFeedEventText<Arc<str>> {
+        self.map_text(&mut |s| interner.intern(s.as_ref()))
+    }
+}
+
+impl<S: Clone + PartialEq> ParsedPlayerFeedEventText<S> {
+    /// Like `==`, but ignores the fields that only record how the text was worded (articles,
+    /// "and also", which attribute-equal phrasing), so the same event parsed from slightly
+    /// different text still compares equal.
+    pub fn semantic_eq(&self, other: &Self) -> bool {
+        self.without_wording() == other.without_wording()
+    }
+
+    fn without_wording(&self) -> Self {
+        let mut event = self.clone();
+        match &mut event {
+            ParsedPlayerFeedEventText::Delivery { delivery }
+            | ParsedPlayerFeedEventText::Shipment { delivery }
+            | ParsedPlayerFeedEventText::SpecialDelivery { delivery } => delivery.article = None,
+            ParsedPlayerFeedEventText::AttributeEquals { phrasing, .. } => *phrasing = None,
+            ParsedPlayerFeedEventText::Enchantment { article, also, .. } => {
+                *article = None;
+                *also = false;
+            }
+            ParsedPlayerFeedEventText::CompensatoryEnchantments { enchantments } => {
+                for enchantment in enchantments {
+                    enchantment.article = None;
+                    enchantment.also = false;
+                }
+            }
+            _ => {}
+        }
+        event
+    }
+}
+
+impl<S: Debug> ParsedPlayerFeedEventText<S> {
+    /// See [`crate::team_feed::ParsedTeamFeedEventText::sort_key`]
+    pub fn sort_key(&self) -> (&'static str, String) {
+        (self.into(), format!("{self:?}"))
+    }
+}
+
 impl<S: Display> ParsedPlayerFeedEventText<S> {
+    /// A short description for logs and debugging, e.g. `AttributeChanges: Nancy Bright +50 Awareness`.
+    /// See [`crate::team_feed::ParsedTeamFeedEventText::summary`].
+    pub fn summary(&self) -> String {
+        let name: &'static str = self.into();
+        let details = match self {
+            ParsedPlayerFeedEventText::ParseError { error, .. } => error.to_string(),
+            ParsedPlayerFeedEventText::Delivery { delivery }
+            | ParsedPlayerFeedEventText::Shipment { delivery }
+            | ParsedPlayerFeedEventText::SpecialDelivery { delivery } => {
+                format!("{} {}", delivery.player, delivery.item)
+            }
+            ParsedPlayerFeedEventText::AttributeChanges {
+                player_name,
+                amount,
+                attribute,
+                ..
+            } => {
+                format!("{player_name} {amount:+} {attribute}")
+            }
+            ParsedPlayerFeedEventText::AttributeEquals {
+                player_name,
+                changing_attribute,
+                value_player,
+                value_attribute,
+                ..
+            } => match value_player {
+                Some(value_player) => {
+                    format!("{player_name} {changing_attribute} = {value_player} {value_attribute}")
+                }
+                None => format!("{player_name} {changing_attribute} = {value_attribute}"),
+            },
+            ParsedPlayerFeedEventText::Enchantment {
+                player_name,
+                item,
+                enchants,
+                ..
+            } => {
+                let bonuses = enchants
+                    .iter()
+                    .map(|(amount, attribute)| format!("+{amount} {attribute}"))
+                    .join(", ");
+                format!("{player_name}'s {item} {bonuses}")
+            }
+            ParsedPlayerFeedEventText::Recomposed { previous, new } => {
+                format!("{previous} -> {new}")
+            }
+            ParsedPlayerFeedEventText::Modification {
+                player_name,
+                modification,
+                additional_modifications,
+                ..
+            } => format!(
+                "{player_name} {}",
+                std::iter::once(modification)
+                    .chain(additional_modifications)
+                    .join(", ")
+            ),
+            ParsedPlayerFeedEventText::SeasonalDurabilityLoss {
+                player_name,
+                durability_lost,
+                season,
+            } => {
+                let lost = durability_lost
+                    .map(|lost| format!("-{lost}"))
+                    .unwrap_or_else(|| "resisted".to_string());
+                format!("{player_name} {lost} durability, season {season}")
+            }
+            ParsedPlayerFeedEventText::Retirement { previous, .. } => previous.to_string(),
+            ParsedPlayerFeedEventText::Injury { player_name }
+            | ParsedPlayerFeedEventText::Recovery { player_name } => player_name.to_string(),
+            ParsedPlayerFeedEventText::AllStarSelection { player, team } => {
+                format!("{player} ({team})")
+            }
+            ParsedPlayerFeedEventText::EnteredSuperstarGame { player } => player.to_string(),
+            _ => return name.to_string(),
+        };
+        format!("{name}: {details}")
+    }
+
+    /// See [`crate::team_feed::ParsedTeamFeedEventText::into_feed_event`]
+    pub fn into_feed_event(&self, template: &FeedEvent) -> FeedEvent {
+        let mut event = template.clone();
+        if let Some(event_type) = self.feed_event_type() {
+            event.event_type = Ok(event_type);
+        }
+        // Some unparsing depends on the event type, so this has to come after setting it
+        event.text = self.unparse(&event);
+        event
+    }
+
     pub fn unparse(&self, event: &FeedEvent) -> String {
         match self {
             ParsedPlayerFeedEventText::ParseError { error: _, text } => text.to_string(),
@@ -159,16 +765,36 @@ impl<S: Display> ParsedPlayerFeedEventText<S> {
             ParsedPlayerFeedEventText::FallingStarOutcome { player_name, outcome } => {
                 outcome.unparse(event, player_name)
             }
-            ParsedPlayerFeedEventText::AttributeChanges { player_name, amount, attribute } => format!("{player_name} gained +{amount} {attribute}."),
-            ParsedPlayerFeedEventText::AttributeEquals { player_name, changing_attribute, value_attribute } => {
-                        if Breakpoints::Season3.after(event.season as u32, event.day.as_ref().copied().ok(), None) {
-                            format!("{}'s {} was set to their {}.", player_name, changing_attribute, value_attribute)
-                        } else if Breakpoints::S1AttributeEqualChange.after(event.season as u32, event.day.as_ref().copied().ok(), None) {
-                            format!("{}'s {} became equal to their current base {}.", player_name, changing_attribute, value_attribute)
-                        } else {
-                            format!("{}'s {} was set to their {}.", player_name, changing_attribute, value_attribute)
-                        }
-                    },
+            ParsedPlayerFeedEventText::AllStarSelection { player, team } => {
+                format!("{player} was selected to represent the {team} in the Superstar Game.")
+            }
+            ParsedPlayerFeedEventText::EnteredSuperstarGame { player } => {
+                format!("{player} entered the Superstar Game!")
+            }
+            // Both of these give back the guessed wording, see `injury` in shared
+            ParsedPlayerFeedEventText::Injury { player_name } => {
+                if event.after(Breakpoints::Season5TenseChange) {
+                    format!("{player_name} is injured!")
+                } else {
+                    format!("{player_name} was injured!")
+                }
+            }
+            ParsedPlayerFeedEventText::Recovery { player_name } => {
+                if event.after(Breakpoints::Season5TenseChange) {
+                    format!("{player_name} is fully healed!")
+                } else {
+                    format!("{player_name} recovered from their injury!")
+                }
+            }
+            ParsedPlayerFeedEventText::AttributeChanges { player_name, amount, attribute, multiplier } => {
+                let multiplier = multiplier.map(|multiplier| format!(" x{multiplier}")).unwrap_or_default();
+                format!("{player_name} gained +{amount} {attribute}{multiplier}.")
+            },
+            ParsedPlayerFeedEventText::AttributeEquals { player_name, changing_attribute, value_player, value_attribute, phrasing } => {
+                let owner = value_player.as_ref().map(|value_player| format!("{value_player}'s")).unwrap_or_else(|| "their".to_string());
+                let phrasing = phrasing.unwrap_or_else(|| AttributeEqualPhrasing::for_event(event));
+                format!("{player_name}'s {changing_attribute} {} {owner} {}{value_attribute}.", phrasing.verb(), phrasing.base())
+            },
             ParsedPlayerFeedEventText::Recomposed { previous, new } => {
                         if event.timestamp > Timestamp::Season3RecomposeChange.timestamp() {
                             format!("{previous} was Recomposed into {new}.")
@@ -179,36 +805,35 @@ impl<S: Display> ParsedPlayerFeedEventText<S> {
             ParsedPlayerFeedEventText::TakeTheMound { to_mound_player, to_lineup_player } => format!("{to_mound_player} was moved to the mound. {to_lineup_player} was sent to the lineup."),
             ParsedPlayerFeedEventText::TakeThePlate { to_plate_player, from_lineup_player } => format!("{to_plate_player} was sent to the plate. {from_lineup_player} was pulled from the lineup."),
             ParsedPlayerFeedEventText::SwapPlaces { player_one, player_two } => format!("{player_one} swapped places with {player_two}."),
-            ParsedPlayerFeedEventText::Enchantment { player_name, item, amount, attribute, enchant_two, compensatory } => {
-                        if event.before(Breakpoints::Season1EnchantmentChange) {
-                            if enchant_two.is_some() {
-                                tracing::error!("Season 1 enchantment had two enchants");
+            ParsedPlayerFeedEventText::Enchantment { player_name, item, article, enchants, compensatory, also } => {
+                        if event.before(Breakpoints::season(2)) {
+                            if enchants.len() > 1 {
+                                tracing::error!("Season 1 enchantment had multiple enchants");
                             }
                             if *compensatory {
                                 tracing::error!("Season 1 enchantment was compensatory")
                             }
-                            format!("{player_name}'s {item} was enchanted with +{amount} to {attribute}.")
-                        } else if event.before(Breakpoints::season(2)) {
-                            if enchant_two.is_some() {
-                                tracing::error!("Season 1 enchantment had two enchants");
-                            }
-                            if *compensatory {
-                                tracing::error!("Season 1 enchantment was compensatory")
-                            }
-                            format!("The Item Enchantment was a success! {player_name}'s {item} gained a +{amount} {attribute} bonus.")
+                        }
+
+                        if event.before(Breakpoints::Season1EnchantmentChange) {
+                            let bonuses = enchants.iter().map(|(amount, attribute)| format!("+{amount} to {attribute}")).join(" and ");
+                            format!("{player_name}'s {item} was enchanted with {bonuses}.")
                         } else {
-                            let enchant_type = compensatory.then_some("Compensatory").unwrap_or("Item");
-                            match enchant_two {
-                                Some((amount_two, attribute_two)) => format!("The {enchant_type} Enchantment was a success! {player_name}'s {item} was enchanted with +{amount} {attribute} and +{amount_two} {attribute_two}."),
-                                None =>  format!("The {enchant_type} Enchantment was a success! {player_name}'s {item} gained a +{amount} {attribute} bonus.")
-                            }
+                            unparse_enchantment(*compensatory, player_name, item, article.as_ref(), enchants, *also)
                         }
                     },
+            ParsedPlayerFeedEventText::CompensatoryEnchantments { enchantments } => {
+                enchantments.iter().map(ItemEnchantment::unparse_compensatory).join(" ")
+            },
+            ParsedPlayerFeedEventText::Disenchantment { player_name, item, amount, attribute } => {
+                format!("{player_name}'s {item} lost its +{amount} {attribute} bonus.")
+            },
             ParsedPlayerFeedEventText::Released { team } => format!("Released by the {team}."),
-            ParsedPlayerFeedEventText::Modification { player_name, lost_modification, modification } => {
+            ParsedPlayerFeedEventText::Modification { player_name, lost_modification, modification, additional_modifications } => {
+                let modifications = unparse_modification_list(modification, additional_modifications);
                 match lost_modification {
-                    Some(lost_modification) => format!("{player_name} lost the {lost_modification} Modification. {player_name} gained the {modification} Modification."),
-                    None => format!("{player_name} gained the {modification} Modification.")
+                    Some(lost_modification) => format!("{player_name} lost the {lost_modification} Modification. {player_name} gained {modifications}"),
+                    None => format!("{player_name} gained {modifications}")
                 }
             },
             ParsedPlayerFeedEventText::Retirement { previous, new } => {
@@ -245,6 +870,10 @@ impl<S: Display> ParsedPlayerFeedEventText<S> {
             ParsedPlayerFeedEventText::PlayerGrow { grow } => {
                 format!("{grow}")
             }
+            ParsedPlayerFeedEventText::AttributeSet { set } => set.to_string(),
+            ParsedPlayerFeedEventText::ItemModification { modification } => {
+                modification.to_string()
+            }
             ParsedPlayerFeedEventText::GreaterAugment { player_name, greater_augment } => {
                 match greater_augment {
                     PlayerGreaterAugment::Headliners { attribute } => format!("{player_name} gained +75 {attribute}."),
@@ -278,3 +907,268 @@ impl<S: Display> ParsedPlayerFeedEventText<S> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use crate::{
+        enums::FeedEventSource,
+        utils::{assert_feeds_parse_or_allowlisted, feed_event},
+    };
+
+    #[test]
+    fn raw_player_feeds_parse() -> Result<(), Box<dyn std::error::Error>> {
+        assert_feeds_parse_or_allowlisted(
+            Path::new("test_data/raw/player_feed"),
+            FeedEventSource::Player,
+        )
+    }
+
+    #[test]
+    fn recompose_round_trip() {
+        use super::ParsedPlayerFeedEventText;
+        use crate::time::Timestamp;
+
+        let change = Timestamp::Season3RecomposeChange.timestamp();
+        for (text, timestamp) in [
+            (
+                "Yumi Pratt was Recomposed using Kelly Vieira.",
+                change - chrono::Duration::days(1),
+            ),
+            (
+                "Yumi Pratt was Recomposed into Kelly Vieira.",
+                change + chrono::Duration::days(1),
+            ),
+        ] {
+            let mut event = feed_event(3, 40, "augment", text);
+            event.timestamp = timestamp;
+            let parsed = super::parse_player_feed_event(&event);
+            assert_eq!(
+                parsed,
+                ParsedPlayerFeedEventText::Recomposed {
+                    previous: "Yumi Pratt",
+                    new: "Kelly Vieira",
+                }
+            );
+            assert_eq!(parsed.unparse(&event), text);
+        }
+    }
+
+    #[test]
+    fn attribute_change_is_borrowed() {
+        use super::ParsedPlayerFeedEventText;
+        use crate::enums::Attribute;
+
+        let parsed = ParsedPlayerFeedEventText::AttributeChanges {
+            player_name: String::from("Yumi Pratt"),
+            amount: 5,
+            attribute: Attribute::Contact,
+            multiplier: None,
+        };
+
+        let change = parsed
+            .attribute_change()
+            .expect("should have an attribute change");
+        assert_eq!(change.attribute, Attribute::Contact);
+        assert_eq!(change.total(), 5);
+        let ParsedPlayerFeedEventText::AttributeChanges {
+            player_name: owned, ..
+        } = &parsed
+        else {
+            unreachable!()
+        };
+        assert!(std::ptr::eq(change.player_name, owned));
+    }
+
+    #[test]
+    fn interned_names_share_storage() {
+        use super::ParsedPlayerFeedEventText;
+
+        let feed = [
+            feed_event(
+                3,
+                40,
+                "augment",
+                "Yumi Pratt was Recomposed using Kelly Vieira.",
+            ),
+            feed_event(5, 40, "augment", "Kelly Vieira retired from MMOLB!"),
+        ];
+
+        let (parsed, interner) = super::parse_player_feed_events_interned(&feed);
+        assert_eq!(interner.len(), 2);
+
+        let [ParsedPlayerFeedEventText::Recomposed { new, .. }, ParsedPlayerFeedEventText::Retirement { previous, .. }] =
+            parsed.as_slice()
+        else {
+            panic!("expected a recompose and a retirement, got {parsed:?}");
+        };
+        assert!(std::sync::Arc::ptr_eq(new, previous));
+    }
+
+    #[test]
+    fn augment_retirement_round_trip() {
+        use super::ParsedPlayerFeedEventText;
+
+        for (text, new) in [
+            ("Yumi Pratt retired from MMOLB!", None),
+            (
+                "Yumi Pratt retired from MMOLB! Kelly Vieira was called up to take their place.",
+                Some("Kelly Vieira"),
+            ),
+        ] {
+            let event = feed_event(5, 40, "augment", text);
+            let parsed = super::parse_player_feed_event(&event);
+            assert_eq!(
+                parsed,
+                ParsedPlayerFeedEventText::Retirement {
+                    previous: "Yumi Pratt",
+                    new,
+                }
+            );
+            assert_eq!(parsed.unparse(&event), text);
+        }
+    }
+
+    #[test]
+    fn item_modification() {
+        use super::ParsedPlayerFeedEventText;
+        use crate::feed_event::ItemModChange;
+
+        // The wording here is a guess (see `item_modification` in shared), so this only checks
+        // that the guess is wired up. Add round trips once real lines are captured.
+        for text in [
+            "Nancy Bright's Cap became Sharp.",
+            "Nancy Bright's Sharp Cap is no longer Sharp.",
+            "Nancy Bright's Cap of the Cat became of the Acrobat.",
+        ] {
+            let event = feed_event(5, 40, "augment", text);
+            let parsed = super::parse_player_feed_event(&event);
+            assert!(
+                matches!(parsed, ParsedPlayerFeedEventText::ItemModification { .. }),
+                "{parsed:?}"
+            );
+        }
+
+        let event = feed_event(
+            5,
+            40,
+            "augment",
+            "Nancy Bright's Cap of the Cat became of the Acrobat.",
+        );
+        let ParsedPlayerFeedEventText::ItemModification { modification } =
+            super::parse_player_feed_event(&event)
+        else {
+            unreachable!()
+        };
+        assert_eq!(
+            modification.change,
+            ItemModChange::SuffixAdded(crate::enums::ItemSuffix::Acrobat)
+        );
+    }
+
+    #[test]
+    fn attribute_set() {
+        use super::ParsedPlayerFeedEventText;
+        use crate::{enums::Attribute, feed_event::AttributeSet};
+
+        // The wording here is a guess (see `attribute_set` in shared), so this only checks that
+        // the guess is wired up. Add round trips once real lines are captured.
+        let event = feed_event(4, 10, "augment", "Nancy Bright's Awareness dropped to 40.");
+        let parsed = super::parse_player_feed_event(&event);
+        assert_eq!(
+            parsed,
+            ParsedPlayerFeedEventText::AttributeSet {
+                set: AttributeSet {
+                    player_name: "Nancy Bright",
+                    attribute: Attribute::Awareness,
+                    value: 40,
+                }
+            }
+        );
+
+        // Doesn't get in the way of setting an attribute to another attribute
+        let event = feed_event(
+            4,
+            10,
+            "augment",
+            "Nancy Bright's Awareness was set to their Aiming.",
+        );
+        assert!(matches!(
+            super::parse_player_feed_event(&event),
+            ParsedPlayerFeedEventText::AttributeEquals { .. }
+        ));
+    }
+
+    #[test]
+    fn semantic_eq_ignores_phrasing() {
+        use super::ParsedPlayerFeedEventText;
+        use crate::{enums::Attribute, feed_event::AttributeEqualPhrasing};
+
+        let equals = |phrasing, value_attribute| ParsedPlayerFeedEventText::AttributeEquals {
+            player_name: "Nancy Bright",
+            changing_attribute: Attribute::Contact,
+            value_player: None,
+            value_attribute,
+            phrasing,
+        };
+        let set_to = equals(Some(AttributeEqualPhrasing::SetTo), Attribute::Aiming);
+        let base = equals(
+            Some(AttributeEqualPhrasing::BecameEqualToBase),
+            Attribute::Aiming,
+        );
+        assert_ne!(set_to, base);
+        assert!(set_to.semantic_eq(&base));
+        assert!(!set_to.semantic_eq(&equals(
+            Some(AttributeEqualPhrasing::SetTo),
+            Attribute::Vision
+        )));
+    }
+
+    #[test]
+    fn attribute_equals_round_trip() {
+        use super::ParsedPlayerFeedEventText;
+        use crate::{enums::Attribute, feed_event::AttributeEqualPhrasing};
+
+        let cases = [
+            (
+                "Nancy Bright's Contact was set to their Aiming.",
+                None,
+                AttributeEqualPhrasing::SetTo,
+            ),
+            (
+                "Nancy Bright's Contact was set to Kelly Vieira's Aiming.",
+                Some("Kelly Vieira"),
+                AttributeEqualPhrasing::SetTo,
+            ),
+            (
+                "Nancy Bright's Contact became equal to their base Aiming.",
+                None,
+                AttributeEqualPhrasing::BecameEqualToBase,
+            ),
+            (
+                "Nancy Bright's Contact became equal to Kelly Vieira's current base Aiming.",
+                Some("Kelly Vieira"),
+                AttributeEqualPhrasing::BecameEqualToCurrentBase,
+            ),
+        ];
+        // Every phrasing comes back out as itself, whichever one was in use at the time
+        for season in [0, 1, 2, 4] {
+            for (text, expected_value_player, expected_phrasing) in cases {
+                let event = feed_event(season, 10, "augment", text);
+                let parsed = super::parse_player_feed_event(&event);
+                assert_eq!(
+                    parsed,
+                    ParsedPlayerFeedEventText::AttributeEquals {
+                        player_name: "Nancy Bright",
+                        changing_attribute: Attribute::Contact,
+                        value_player: expected_value_player,
+                        value_attribute: Attribute::Aiming,
+                        phrasing: Some(expected_phrasing),
+                    }
+                );
+                assert_eq!(parsed.unparse(&event), text);
+            }
+        }
+    }
+}
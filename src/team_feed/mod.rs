@@ -1,23 +1,104 @@
-use std::fmt::Display;
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::sync::Arc;
 
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
+use strum::IntoStaticStr;
 
 use crate::enums::Slot;
-use crate::feed_event::{AttributeChange, GreaterAugment};
-pub use crate::nom_parsing::parse_team_feed_event::parse_team_feed_event;
-use crate::nom_parsing::shared::{FeedEventDoorPrize, FeedEventParty, Grow, PositionSwap};
+use crate::feed_event::{
+    aggregate_changes, AttributeChange, AttributeOperation, AttributeSet, GreaterAugment,
+    ItemModification,
+};
+pub use crate::nom_parsing::parse_team_feed_event::{
+    parse_team_feed_event, parse_team_feed_event_with, parse_team_feed_event_with_source,
+};
+use crate::nom_parsing::shared::{
+    unparse_modification_list, unparse_thousands_separated, FeedEventDoorPrize, FeedEventParty,
+    Grow, PositionSwap,
+};
 use crate::parsed_event::{EmojiPlayer, EmojiTeam, GrowAttributeChange};
 use crate::{
-    enums::{Attribute, FeedEventType, ModificationType},
+    enums::{Attribute, AttributeCategory, Currency, FeedEventType, ModificationType, RosterSlot},
     feed_event::{
-        EmojilessItem, FeedDelivery, FeedEvent, FeedEventParseError, FeedFallingStarOutcome,
+        deserialize_enchantment, parse_ndjson, unparse_enchantment, DeliveryKind, EmojilessItem,
+        FeedDelivery, FeedEvent, FeedEventParseError, FeedFallingStarOutcome, FeedNdjsonError,
+        ItemEnchantment, ParserRegistry, StringInterner,
     },
     time::{Breakpoints, Timestamp},
     utils::extra_fields_deserialize,
 };
 
+/// Like [`parse_team_feed_event`], but the result doesn't borrow from the event.
+pub fn parse_team_feed_event_owned(event: &FeedEvent) -> ParsedTeamFeedEventText<String> {
+    parse_team_feed_event(event).map_text(&mut |s| s.to_string())
+}
+
+/// Parses every event, copying the strings into a [`StringInterner`] so names that repeat across
+/// the events (which is most of them) are only stored once. Handy for holding a lot of parsed
+/// events in memory at once.
+pub fn parse_team_feed_events_interned(
+    events: &[FeedEvent],
+) -> (Vec<ParsedTeamFeedEventText<Arc<str>>>, StringInterner) {
+    let mut interner = StringInterner::new();
+    let parsed = events
+        .iter()
+        .map(|event| parse_team_feed_event(event).intern(&mut interner))
+        .collect();
+    (parsed, interner)
+}
+
+/// Totals up [`ParsedTeamFeedEventText::net_changes`] across all of `events`, per player and
+/// attribute. Parse errors and events that don't change attributes are skipped.
+pub fn net_attribute_changes<'a, S: Eq + Hash + Clone + 'a>(
+    events: impl IntoIterator<Item = &'a ParsedTeamFeedEventText<S>>,
+) -> HashMap<(S, Attribute), i32> {
+    let changes = events
+        .into_iter()
+        .flat_map(ParsedTeamFeedEventText::net_changes)
+        .collect::<Vec<_>>();
+    aggregate_changes(&changes)
+}
+
+/// Like [`parse_team_feed_event`], but the text is copied into `arena` first, so the result
+/// borrows from the arena instead of the event. Lots of events can be parsed into one arena and
+/// freed all at once. Any `Vec`s in the result and the text in a `FailedParsingText` error still
+/// come from the global allocator.
+#[cfg(feature = "bumpalo")]
+pub fn parse_team_feed_event_in<'a>(
+    event: &FeedEvent,
+    arena: &'a bumpalo::Bump,
+) -> ParsedTeamFeedEventText<&'a str> {
+    crate::nom_parsing::parse_team_feed_event::parse_team_feed_event_text(
+        event,
+        arena.alloc_str(&event.text),
+        &crate::feed_event::ParseOptions::default(),
+    )
+}
+
+/// Like [`parse_team_feed_event_owned`], but tries the parsers in `registry` for the event's type first.
+pub fn parse_team_feed_event_with_registry(
+    event: &FeedEvent,
+    registry: &ParserRegistry<ParsedTeamFeedEventText<String>>,
+) -> ParsedTeamFeedEventText<String> {
+    event
+        .event_type
+        .as_ref()
+        .ok()
+        .and_then(|event_type| registry.parse(*event_type, &event.text))
+        .unwrap_or_else(|| parse_team_feed_event_owned(event))
+}
+
+/// Parses a newline-delimited dump of team feed events one line at a time.
+pub fn parse_team_feed_events_ndjson<R: std::io::BufRead>(
+    reader: R,
+) -> impl Iterator<Item = Result<ParsedTeamFeedEventText<String>, FeedNdjsonError>> {
+    parse_ndjson(reader, parse_team_feed_event_owned)
+}
+
 #[serde_as]
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct TeamFeed {
@@ -27,6 +108,160 @@ pub struct TeamFeed {
     pub extra_fields: serde_json::Map<String, serde_json::Value>,
 }
 
+/// Parse a team feed and pick out particular kinds of events, e.g.
+/// `feed.feed.iter().enchantments()`.
+pub trait FeedEventIteratorExt<'a>: Iterator<Item = &'a FeedEvent> + Sized {
+    fn parsed(self) -> impl Iterator<Item = ParsedTeamFeedEventText<&'a str>> {
+        self.map(parse_team_feed_event)
+    }
+
+    /// (away team, home team, away score, home score), in the order they appear in the text
+    fn game_results(
+        self,
+    ) -> impl Iterator<Item = (EmojiTeam<&'a str>, EmojiTeam<&'a str>, u16, u16)> {
+        self.parsed().filter_map(|parsed| match parsed {
+            ParsedTeamFeedEventText::GameResult {
+                home_team,
+                away_team,
+                home_score,
+                away_score,
+                ..
+            } => Some((away_team, home_team, away_score, home_score)),
+            _ => None,
+        })
+    }
+
+    /// Every individual change, flattened out of the events they were announced in
+    fn attribute_changes(self) -> impl Iterator<Item = AttributeChange<&'a str>> {
+        self.parsed().flat_map(|parsed| match parsed {
+            ParsedTeamFeedEventText::AttributeChanges { changes } => changes,
+            _ => Vec::new(),
+        })
+    }
+
+    /// Like [`attribute_changes`](Self::attribute_changes), but only the changes to
+    /// attributes in `category`
+    fn attribute_changes_in(
+        self,
+        category: AttributeCategory,
+    ) -> impl Iterator<Item = AttributeChange<&'a str>> {
+        self.attribute_changes()
+            .filter(move |change| change.attribute.category() == Some(category))
+    }
+
+    fn batting_changes(self) -> impl Iterator<Item = AttributeChange<&'a str>> {
+        self.attribute_changes_in(AttributeCategory::Batting)
+    }
+
+    fn pitching_changes(self) -> impl Iterator<Item = AttributeChange<&'a str>> {
+        self.attribute_changes_in(AttributeCategory::Pitching)
+    }
+
+    fn defense_changes(self) -> impl Iterator<Item = AttributeChange<&'a str>> {
+        self.attribute_changes_in(AttributeCategory::Defense)
+    }
+
+    fn baserunning_changes(self) -> impl Iterator<Item = AttributeChange<&'a str>> {
+        self.attribute_changes_in(AttributeCategory::Baserunning)
+    }
+
+    /// (whose item it is, item, bonuses)
+    fn enchantments(self) -> impl Iterator<Item = (&'a str, EmojilessItem, Vec<(u8, Attribute)>)> {
+        self.parsed().filter_map(|parsed| match parsed {
+            ParsedTeamFeedEventText::Enchantment {
+                team_name,
+                item,
+                enchants,
+                ..
+            } => Some((team_name, item, enchants)),
+            _ => None,
+        })
+    }
+}
+
+impl<'a, I: Iterator<Item = &'a FeedEvent>> FeedEventIteratorExt<'a> for I {}
+
+/// Who an attribute change applies to. Only the all-players wording has been seen so far;
+/// changes to named players are parsed as [`ParsedTeamFeedEventText::AttributeChanges`].
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum AttributeTarget<S> {
+    Player(S),
+    AllPlayers,
+    /// Speculative: no line for this has been captured, so "All batters" is a guess and may
+    /// never match anything.
+    Lineup,
+    /// Speculative: no line for this has been captured, so "All pitchers" is a guess and may
+    /// never match anything.
+    Rotation,
+}
+
+impl<S> AttributeTarget<S> {
+    pub fn map_text<T>(&self, f: &mut impl FnMut(&S) -> T) -> AttributeTarget<T> {
+        match self {
+            AttributeTarget::Player(player_name) => AttributeTarget::Player(f(player_name)),
+            AttributeTarget::AllPlayers => AttributeTarget::AllPlayers,
+            AttributeTarget::Lineup => AttributeTarget::Lineup,
+            AttributeTarget::Rotation => AttributeTarget::Rotation,
+        }
+    }
+}
+
+impl<S: Display> Display for AttributeTarget<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttributeTarget::Player(player_name) => write!(f, "{player_name}"),
+            AttributeTarget::AllPlayers => write!(f, "All players"),
+            AttributeTarget::Lineup => write!(f, "All batters"),
+            AttributeTarget::Rotation => write!(f, "All pitchers"),
+        }
+    }
+}
+
+/// The parenthesized note some game results have after the score
+///
+/// Speculative: no annotated game result has been captured, so these wordings are guesses and may
+/// never match anything.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum GameAnnotation {
+    /// "(10 innings)". Holds the number of innings played.
+    ExtraInnings(u8),
+    /// "(forfeit)"
+    Forfeit,
+}
+
+impl Display for GameAnnotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameAnnotation::ExtraInnings(innings) => write!(f, "({innings} innings)"),
+            GameAnnotation::Forfeit => write!(f, "(forfeit)"),
+        }
+    }
+}
+
+/// Dashes other than the usual "-" between the scores of a game result
+///
+/// Speculative: only the plain "-" has been captured, so these are guesses and may never match
+/// anything.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ScoreSeparator {
+    /// " - "
+    SpacedHyphen,
+    /// "–"
+    EnDash,
+    /// "—"
+    EmDash,
+}
+
+impl ScoreSeparator {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ScoreSeparator::SpacedHyphen => " - ",
+            ScoreSeparator::EnDash => "–",
+            ScoreSeparator::EmDash => "—",
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub enum PurifiedOutcome {
     Payment(u32),
@@ -46,7 +281,7 @@ impl PurifiedOutcome {
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, IntoStaticStr)]
 pub enum ParsedTeamFeedEventText<S> {
     ParseError {
         error: FeedEventParseError,
@@ -58,8 +293,14 @@ pub enum ParsedTeamFeedEventText<S> {
         /// Sometimes this name is wrong: early season 1 bug where the events didn't have spaces between words.
         away_team: EmojiTeam<S>,
 
-        home_score: u8,
-        away_score: u8,
+        home_score: u16,
+        away_score: u16,
+        /// None for the usual plain "FINAL X-Y". Speculative, see [`GameAnnotation`].
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        annotation: Option<GameAnnotation>,
+        /// None for the usual "-". Speculative, see [`ScoreSeparator`].
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        score_separator: Option<ScoreSeparator>,
     },
     Delivery {
         delivery: FeedDelivery<S>,
@@ -84,6 +325,16 @@ pub enum ParsedTeamFeedEventText<S> {
         team: EmojiTeam<S>,
         income: u32,
     },
+    /// Speculative: no real line for this has been captured, so the wording it's parsed from is
+    /// a guess and may never match anything.
+    Payout {
+        recipient: S,
+        amount: u32,
+        /// Whether the amount was written with commas, e.g. "1,250"
+        #[serde(default)]
+        thousands_separators: bool,
+        currency: Currency,
+    },
     DonatedToLottery {
         team_name: S,
         amount: u32,
@@ -93,17 +344,60 @@ pub enum ParsedTeamFeedEventText<S> {
         amount: u32,
         league_name: S,
     },
+    /// Also loads the `S1Enchantment` and `S2Enchantment` shapes that [`ParsedFeedEventText`]
+    /// serialized, so events stored before the split still load.
+    ///
+    /// [`ParsedFeedEventText`]: crate::feed_event::ParsedFeedEventText
+    #[serde(
+        alias = "S1Enchantment",
+        alias = "S2Enchantment",
+        deserialize_with = "deserialize_enchantment"
+    )]
     Enchantment {
+        team_name: S,
+        item: EmojilessItem,
+        /// The "a" or "an" before a list of bonuses, if there was one
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        article: Option<S>,
+        /// Amount and attribute of each bonus. Always at least one.
+        enchants: Vec<(u8, Attribute)>,
+        compensatory: bool,
+        /// The last of `enchants` came from a trailing "and also gained +N Attribute" clause.
+        ///
+        /// Speculative: no enchantment with that clause has been captured, so the wording is a
+        /// guess and may never match anything.
+        #[serde(default)]
+        also: bool,
+    },
+    /// A compensatory enchantment that hit more than one item, one sentence per item. A single
+    /// item is parsed as a compensatory `Enchantment`.
+    CompensatoryEnchantments {
+        enchantments: Vec<ItemEnchantment<S>>,
+    },
+    /// An item losing one of its bonuses. Named like `Enchantment`, so `team_name` is whoever
+    /// owns the item.
+    ///
+    /// Speculative: no disenchantment line has been captured, so the wording this is parsed from
+    /// is a guess and may never match anything.
+    Disenchantment {
         team_name: S,
         item: EmojilessItem,
         amount: u8,
         attribute: Attribute,
-        enchant_two: Option<(u8, Attribute)>,
-        compensatory: bool,
     },
     AttributeChanges {
         changes: Vec<AttributeChange<S>>,
     },
+    /// Attribute changes that mix gains, losses, and equals. Events with only gains are
+    /// `AttributeChanges` and events with only equals are `MassAttributeEquals`.
+    AttributeOperations {
+        operations: Vec<AttributeOperation<S>>,
+    },
+    TeamAttributeChange {
+        target: AttributeTarget<S>,
+        amount: i16,
+        attribute: Attribute,
+    },
     MassAttributeEquals {
         players: Vec<(Option<Slot>, S)>,
         changing_attribute: Attribute,
@@ -129,6 +423,9 @@ pub enum ParsedTeamFeedEventText<S> {
         team_name: S,
         lost_modification: Option<ModificationType>,
         modification: ModificationType,
+        /// Any modifications after the first, for events that grant more than one
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        additional_modifications: Vec<ModificationType>,
     },
     FallingStarOutcome {
         player_name: S,
@@ -137,6 +434,17 @@ pub enum ParsedTeamFeedEventText<S> {
     CorruptedByWither {
         player_name: S,
     },
+    /// An injury that didn't come from a Falling Star.
+    ///
+    /// Speculative: no real line for this has been captured, so the wording it's parsed from is
+    /// a guess and may never match anything.
+    Injury {
+        player_name: S,
+    },
+    /// Speculative, same as `Injury`
+    Recovery {
+        player_name: S,
+    },
     Purified {
         player_name: S,
         outcome: PurifiedOutcome,
@@ -149,6 +457,16 @@ pub enum ParsedTeamFeedEventText<S> {
     PlayerRelegated {
         player_name: S,
     },
+    /// Speculative: no promotion or demotion line has been captured, so the wording this is
+    /// parsed from is a guess and may never match anything.
+    RosterMove {
+        team_emoji: S,
+        player_name: S,
+        /// "promoted" if true, otherwise "demoted"
+        promoted: bool,
+        from: RosterSlot,
+        to: RosterSlot,
+    },
     PlayerPositionsSwapped {
         swap: PositionSwap<S>,
     },
@@ -159,6 +477,15 @@ pub enum ParsedTeamFeedEventText<S> {
     PlayerGrow {
         grow: Grow<S>,
     },
+    /// An attribute set to a number instead of to another attribute. Speculative, see
+    /// [`AttributeSet`].
+    AttributeSet {
+        set: AttributeSet<S>,
+    },
+    /// An item gaining or losing a prefix or suffix. Speculative, see [`ItemModification`].
+    ItemModification {
+        modification: ItemModification<S>,
+    },
     Callup {
         lesser_league_team: EmojiTeam<S>,
         greater_league_team: EmojiTeam<S>,
@@ -185,6 +512,25 @@ pub enum ParsedTeamFeedEventText<S> {
         team: EmojiTeam<S>,
         new_belt_holder_team: EmojiTeam<S>,
     },
+    SeasonBegan {
+        /// Taken from the text, not the event's `season`, since the announcement can be posted
+        /// before the feed event's metadata has rolled over.
+        season: u32,
+    },
+    PostseasonBegan,
+    /// A player picked to play in the Superstar Game.
+    ///
+    /// Speculative: no real line for this has been captured, so the wording it's parsed from is
+    /// a guess and may never match anything.
+    AllStarSelection {
+        player: S,
+        team: S,
+    },
+    /// A player selected for the Superstar Game taking the field in it. Speculative, like
+    /// `AllStarSelection`.
+    EnteredSuperstarGame {
+        player: S,
+    },
     // TODO Delete any of these that are still unused when parsing is up to date
     Released {
         team: S,
@@ -195,12 +541,758 @@ pub enum ParsedTeamFeedEventText<S> {
     },
 }
 
+impl<S> ParsedTeamFeedEventText<S> {
+    /// The delivery for any of the three delivery-shaped variants, so they can be handled alike
+    pub fn delivery(&self) -> Option<(DeliveryKind, &FeedDelivery<S>)> {
+        match self {
+            ParsedTeamFeedEventText::Delivery { delivery } => {
+                Some((DeliveryKind::Delivery, delivery))
+            }
+            ParsedTeamFeedEventText::Shipment { delivery } => {
+                Some((DeliveryKind::Shipment, delivery))
+            }
+            ParsedTeamFeedEventText::SpecialDelivery { delivery } => {
+                Some((DeliveryKind::SpecialDelivery, delivery))
+            }
+            _ => None,
+        }
+    }
+
+    /// The changes in an [`ParsedTeamFeedEventText::AttributeChanges`] event, borrowed. None for
+    /// any other event.
+    pub fn attribute_changes(&self) -> Option<&[AttributeChange<S>]> {
+        match self {
+            ParsedTeamFeedEventText::AttributeChanges { changes } => Some(changes),
+            _ => None,
+        }
+    }
+
+    /// False for a `ParseError`, whether that's an unknown event type or text none of the parsers
+    /// recognized. Handy as the guard on a wildcard arm, see the README.
+    pub fn is_known(&self) -> bool {
+        !matches!(self, ParsedTeamFeedEventText::ParseError { .. })
+    }
+
+    /// How many runs a [`ParsedTeamFeedEventText::GameResult`] was won by. Uses the same home
+    /// and away convention as [`crate::game::Event::score_differential`]. None for any other event.
+    pub fn margin(&self) -> Option<u16> {
+        match self {
+            ParsedTeamFeedEventText::GameResult {
+                home_score,
+                away_score,
+                ..
+            } => Some(home_score.abs_diff(*away_score)),
+            _ => None,
+        }
+    }
+
+    /// The type of feed event this variant is parsed from. None for parse errors that didn't get
+    /// as far as the text, and for retirements, which show up in both game and season events.
+    pub fn feed_event_type(&self) -> Option<FeedEventType> {
+        match self {
+            ParsedTeamFeedEventText::ParseError {
+                error: FeedEventParseError::FailedParsingText { event_type, .. },
+                ..
+            } => Some(*event_type),
+            ParsedTeamFeedEventText::ParseError { .. }
+            | ParsedTeamFeedEventText::Retirement { .. } => None,
+            ParsedTeamFeedEventText::GameResult { .. }
+            | ParsedTeamFeedEventText::Delivery { .. }
+            | ParsedTeamFeedEventText::Shipment { .. }
+            | ParsedTeamFeedEventText::SpecialDelivery { .. }
+            | ParsedTeamFeedEventText::PhotoContest { .. }
+            | ParsedTeamFeedEventText::Party { .. }
+            | ParsedTeamFeedEventText::DoorPrize { .. }
+            | ParsedTeamFeedEventText::Prosperous { .. }
+            | ParsedTeamFeedEventText::Payout { .. }
+            | ParsedTeamFeedEventText::FallingStarOutcome { .. }
+            | ParsedTeamFeedEventText::CorruptedByWither { .. }
+            | ParsedTeamFeedEventText::Injury { .. }
+            | ParsedTeamFeedEventText::Recovery { .. }
+            | ParsedTeamFeedEventText::PlayerContained { .. }
+            | ParsedTeamFeedEventText::PlayerGrewInEfflorescence { .. }
+            | ParsedTeamFeedEventText::PlayerEffloresce { .. }
+            | ParsedTeamFeedEventText::ClaimedLinealBelt { .. }
+            | ParsedTeamFeedEventText::LostLinealBelt { .. } => Some(FeedEventType::Game),
+            ParsedTeamFeedEventText::Enchantment { .. }
+            | ParsedTeamFeedEventText::CompensatoryEnchantments { .. }
+            | ParsedTeamFeedEventText::Disenchantment { .. }
+            | ParsedTeamFeedEventText::AttributeChanges { .. }
+            | ParsedTeamFeedEventText::AttributeOperations { .. }
+            | ParsedTeamFeedEventText::TeamAttributeChange { .. }
+            | ParsedTeamFeedEventText::MassAttributeEquals { .. }
+            | ParsedTeamFeedEventText::TakeTheMound { .. }
+            | ParsedTeamFeedEventText::TakeThePlate { .. }
+            | ParsedTeamFeedEventText::SwapPlaces { .. }
+            | ParsedTeamFeedEventText::Recomposed { .. }
+            | ParsedTeamFeedEventText::Modification { .. }
+            | ParsedTeamFeedEventText::Purified { .. }
+            | ParsedTeamFeedEventText::PlayerPositionsSwapped { .. }
+            | ParsedTeamFeedEventText::PlayerGrow { .. }
+            | ParsedTeamFeedEventText::AttributeSet { .. }
+            | ParsedTeamFeedEventText::ItemModification { .. } => Some(FeedEventType::Augment),
+            ParsedTeamFeedEventText::Released { .. } => Some(FeedEventType::Release),
+            ParsedTeamFeedEventText::SeasonBegan { .. }
+            | ParsedTeamFeedEventText::PostseasonBegan
+            | ParsedTeamFeedEventText::AllStarSelection { .. }
+            | ParsedTeamFeedEventText::EnteredSuperstarGame { .. } => Some(FeedEventType::Season),
+            ParsedTeamFeedEventText::DonatedToLottery { .. }
+            | ParsedTeamFeedEventText::WonLottery { .. } => Some(FeedEventType::Lottery),
+            ParsedTeamFeedEventText::NameChanged => Some(FeedEventType::Maintenance),
+            ParsedTeamFeedEventText::PlayerMoved { .. }
+            | ParsedTeamFeedEventText::PlayerRelegated { .. }
+            | ParsedTeamFeedEventText::RosterMove { .. } => Some(FeedEventType::Roster),
+            ParsedTeamFeedEventText::Callup { .. }
+            | ParsedTeamFeedEventText::GreaterAugment { .. } => Some(FeedEventType::Election),
+        }
+    }
+}
+
+impl<S: Clone> ParsedTeamFeedEventText<S> {
+    /// Every change this event made to a named player's attributes, signed so losses are
+    /// negative. Item bonuses count as changes to whoever holds the item. Changes to the whole
+    /// team (see [`AttributeTarget`]) aren't included since they don't name anyone.
+    pub fn net_changes(&self) -> Vec<AttributeChange<S>> {
+        let bonuses = |player_name: &S, enchants: &[(u8, Attribute)]| {
+            enchants
+                .iter()
+                .map(|(amount, attribute)| AttributeChange {
+                    player_name: player_name.clone(),
+                    amount: i16::from(*amount),
+                    attribute: *attribute,
+                    multiplier: None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        match self {
+            ParsedTeamFeedEventText::AttributeChanges { changes } => changes.clone(),
+            ParsedTeamFeedEventText::AttributeOperations { operations } => operations
+                .iter()
+                .filter_map(AttributeOperation::net_change)
+                .collect(),
+            ParsedTeamFeedEventText::TeamAttributeChange {
+                target: AttributeTarget::Player(player_name),
+                amount,
+                attribute,
+            } => vec![AttributeChange {
+                player_name: player_name.clone(),
+                amount: *amount,
+                attribute: *attribute,
+                multiplier: None,
+            }],
+            ParsedTeamFeedEventText::Enchantment {
+                team_name,
+                enchants,
+                ..
+            } => bonuses(team_name, enchants),
+            ParsedTeamFeedEventText::CompensatoryEnchantments { enchantments } => enchantments
+                .iter()
+                .flat_map(|enchantment| bonuses(&enchantment.player_name, &enchantment.enchants))
+                .collect(),
+            ParsedTeamFeedEventText::Disenchantment {
+                team_name,
+                amount,
+                attribute,
+                ..
+            } => vec![AttributeChange {
+                player_name: team_name.clone(),
+                amount: -i16::from(*amount),
+                attribute: *attribute,
+                multiplier: None,
+            }],
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl<S: Clone + PartialEq> ParsedTeamFeedEventText<S> {
+    /// Like `==`, but ignores the fields that only record how the text was worded (articles,
+    /// score separators, "and also", "current base"), so the same event parsed from slightly
+    /// different text still compares equal.
+    pub fn semantic_eq(&self, other: &Self) -> bool {
+        self.without_wording() == other.without_wording()
+    }
+
+    fn without_wording(&self) -> Self {
+        let mut event = self.clone();
+        match &mut event {
+            ParsedTeamFeedEventText::GameResult {
+                score_separator, ..
+            } => *score_separator = None,
+            ParsedTeamFeedEventText::Delivery { delivery }
+            | ParsedTeamFeedEventText::Shipment { delivery }
+            | ParsedTeamFeedEventText::SpecialDelivery { delivery } => delivery.article = None,
+            ParsedTeamFeedEventText::Enchantment { article, also, .. } => {
+                *article = None;
+                *also = false;
+            }
+            ParsedTeamFeedEventText::CompensatoryEnchantments { enchantments } => {
+                for enchantment in enchantments {
+                    enchantment.article = None;
+                    enchantment.also = false;
+                }
+            }
+            ParsedTeamFeedEventText::Payout {
+                thousands_separators,
+                ..
+            } => *thousands_separators = false,
+            ParsedTeamFeedEventText::AttributeOperations { operations } => {
+                for operation in operations {
+                    if let AttributeOperation::Equal { current, .. } = operation {
+                        *current = false;
+                    }
+                }
+            }
+            _ => {}
+        }
+        event
+    }
+}
+
+impl ParsedTeamFeedEventText<String> {
+    /// Puts the spaces back in team names from the early season 1 bug, where game results had
+    /// every space removed from them. A name is only replaced when exactly one of `known_teams`
+    /// has the same name once its own spaces are removed. Does nothing for other events.
+    pub fn reconcile_names(&mut self, known_teams: &[EmojiTeam<String>]) {
+        let ParsedTeamFeedEventText::GameResult {
+            home_team,
+            away_team,
+            ..
+        } = self
+        else {
+            return;
+        };
+
+        for team in [home_team, away_team] {
+            if team.name.contains(' ') {
+                continue;
+            }
+
+            let mut candidates = known_teams
+                .iter()
+                .filter(|known| known.name.replace(' ', "") == team.name);
+            if let (Some(known), None) = (candidates.next(), candidates.next()) {
+                team.name.clone_from(&known.name);
+            }
+        }
+    }
+}
+
+impl<S> ParsedTeamFeedEventText<S> {
+    /// Rebuilds the event with every string passed through `f`, e.g. to copy borrowed text into
+    /// owned `String`s.
+    pub fn map_text<T>(&self, f: &mut impl FnMut(&S) -> T) -> ParsedTeamFeedEventText<T> {
+        match self {
+            ParsedTeamFeedEventText::ParseError { error, text } => {
+                ParsedTeamFeedEventText::ParseError {
+                    error: error.clone(),
+                    text: f(text),
+                }
+            }
+            ParsedTeamFeedEventText::GameResult {
+                home_team,
+                away_team,
+                home_score,
+                away_score,
+                annotation,
+                score_separator,
+            } => ParsedTeamFeedEventText::GameResult {
+                home_team: home_team.map_text(f),
+                away_team: away_team.map_text(f),
+                home_score: *home_score,
+                away_score: *away_score,
+                annotation: *annotation,
+                score_separator: *score_separator,
+            },
+            ParsedTeamFeedEventText::Delivery { delivery } => ParsedTeamFeedEventText::Delivery {
+                delivery: delivery.map_text(f),
+            },
+            ParsedTeamFeedEventText::Shipment { delivery } => ParsedTeamFeedEventText::Shipment {
+                delivery: delivery.map_text(f),
+            },
+            ParsedTeamFeedEventText::SpecialDelivery { delivery } => {
+                ParsedTeamFeedEventText::SpecialDelivery {
+                    delivery: delivery.map_text(f),
+                }
+            }
+            ParsedTeamFeedEventText::PhotoContest {
+                player,
+                earned_coins,
+            } => ParsedTeamFeedEventText::PhotoContest {
+                player: player.as_ref().map(|player| player.map_text(f)),
+                earned_coins: *earned_coins,
+            },
+            ParsedTeamFeedEventText::Party { party } => ParsedTeamFeedEventText::Party {
+                party: party.map_text(f),
+            },
+            ParsedTeamFeedEventText::DoorPrize { prize } => ParsedTeamFeedEventText::DoorPrize {
+                prize: prize.map_text(f),
+            },
+            ParsedTeamFeedEventText::Prosperous { team, income } => {
+                ParsedTeamFeedEventText::Prosperous {
+                    team: team.map_text(f),
+                    income: *income,
+                }
+            }
+            ParsedTeamFeedEventText::Payout {
+                recipient,
+                amount,
+                thousands_separators,
+                currency,
+            } => ParsedTeamFeedEventText::Payout {
+                recipient: f(recipient),
+                amount: *amount,
+                thousands_separators: *thousands_separators,
+                currency: *currency,
+            },
+            ParsedTeamFeedEventText::DonatedToLottery {
+                team_name,
+                amount,
+                league_name,
+            } => ParsedTeamFeedEventText::DonatedToLottery {
+                team_name: f(team_name),
+                amount: *amount,
+                league_name: f(league_name),
+            },
+            ParsedTeamFeedEventText::WonLottery {
+                amount,
+                league_name,
+            } => ParsedTeamFeedEventText::WonLottery {
+                amount: *amount,
+                league_name: f(league_name),
+            },
+            ParsedTeamFeedEventText::Enchantment {
+                team_name,
+                item,
+                article,
+                enchants,
+                compensatory,
+                also,
+            } => ParsedTeamFeedEventText::Enchantment {
+                team_name: f(team_name),
+                item: *item,
+                article: article.as_ref().map(&mut *f),
+                enchants: enchants.clone(),
+                compensatory: *compensatory,
+                also: *also,
+            },
+            ParsedTeamFeedEventText::CompensatoryEnchantments { enchantments } => {
+                ParsedTeamFeedEventText::CompensatoryEnchantments {
+                    enchantments: enchantments
+                        .iter()
+                        .map(|enchantment| enchantment.map_text(f))
+                        .collect(),
+                }
+            }
+            ParsedTeamFeedEventText::Disenchantment {
+                team_name,
+                item,
+                amount,
+                attribute,
+            } => ParsedTeamFeedEventText::Disenchantment {
+                team_name: f(team_name),
+                item: *item,
+                amount: *amount,
+                attribute: *attribute,
+            },
+            ParsedTeamFeedEventText::AttributeChanges { changes } => {
+                ParsedTeamFeedEventText::AttributeChanges {
+                    changes: changes.iter().map(|change| change.map_text(f)).collect(),
+                }
+            }
+            ParsedTeamFeedEventText::AttributeOperations { operations } => {
+                ParsedTeamFeedEventText::AttributeOperations {
+                    operations: operations
+                        .iter()
+                        .map(|operation| operation.map_text(f))
+                        .collect(),
+                }
+            }
+            ParsedTeamFeedEventText::TeamAttributeChange {
+                target,
+                amount,
+                attribute,
+            } => ParsedTeamFeedEventText::TeamAttributeChange {
+                target: target.map_text(f),
+                amount: *amount,
+                attribute: *attribute,
+            },
+            ParsedTeamFeedEventText::MassAttributeEquals {
+                players,
+                changing_attribute,
+                value_attribute,
+            } => ParsedTeamFeedEventText::MassAttributeEquals {
+                players: players
+                    .iter()
+                    .map(|(slot, player)| (*slot, f(player)))
+                    .collect(),
+                changing_attribute: *changing_attribute,
+                value_attribute: *value_attribute,
+            },
+            ParsedTeamFeedEventText::TakeTheMound {
+                to_mound_team,
+                to_lineup_team,
+            } => ParsedTeamFeedEventText::TakeTheMound {
+                to_mound_team: f(to_mound_team),
+                to_lineup_team: f(to_lineup_team),
+            },
+            ParsedTeamFeedEventText::TakeThePlate {
+                to_plate_team,
+                from_lineup_team,
+            } => ParsedTeamFeedEventText::TakeThePlate {
+                to_plate_team: f(to_plate_team),
+                from_lineup_team: f(from_lineup_team),
+            },
+            ParsedTeamFeedEventText::SwapPlaces { team_one, team_two } => {
+                ParsedTeamFeedEventText::SwapPlaces {
+                    team_one: f(team_one),
+                    team_two: f(team_two),
+                }
+            }
+            ParsedTeamFeedEventText::Recomposed { previous, new } => {
+                ParsedTeamFeedEventText::Recomposed {
+                    previous: f(previous),
+                    new: f(new),
+                }
+            }
+            ParsedTeamFeedEventText::Modification {
+                team_name,
+                lost_modification,
+                modification,
+                additional_modifications,
+            } => ParsedTeamFeedEventText::Modification {
+                team_name: f(team_name),
+                lost_modification: lost_modification.clone(),
+                modification: modification.clone(),
+                additional_modifications: additional_modifications.clone(),
+            },
+            ParsedTeamFeedEventText::FallingStarOutcome {
+                player_name,
+                outcome,
+            } => ParsedTeamFeedEventText::FallingStarOutcome {
+                player_name: f(player_name),
+                outcome: *outcome,
+            },
+            ParsedTeamFeedEventText::CorruptedByWither { player_name } => {
+                ParsedTeamFeedEventText::CorruptedByWither {
+                    player_name: f(player_name),
+                }
+            }
+            ParsedTeamFeedEventText::Injury { player_name } => ParsedTeamFeedEventText::Injury {
+                player_name: f(player_name),
+            },
+            ParsedTeamFeedEventText::Recovery { player_name } => {
+                ParsedTeamFeedEventText::Recovery {
+                    player_name: f(player_name),
+                }
+            }
+            ParsedTeamFeedEventText::Purified {
+                player_name,
+                outcome,
+            } => ParsedTeamFeedEventText::Purified {
+                player_name: f(player_name),
+                outcome: outcome.clone(),
+            },
+            ParsedTeamFeedEventText::NameChanged => ParsedTeamFeedEventText::NameChanged,
+            ParsedTeamFeedEventText::PlayerMoved {
+                team_emoji,
+                player_name,
+            } => ParsedTeamFeedEventText::PlayerMoved {
+                team_emoji: f(team_emoji),
+                player_name: f(player_name),
+            },
+            ParsedTeamFeedEventText::PlayerRelegated { player_name } => {
+                ParsedTeamFeedEventText::PlayerRelegated {
+                    player_name: f(player_name),
+                }
+            }
+            ParsedTeamFeedEventText::RosterMove {
+                team_emoji,
+                player_name,
+                promoted,
+                from,
+                to,
+            } => ParsedTeamFeedEventText::RosterMove {
+                team_emoji: f(team_emoji),
+                player_name: f(player_name),
+                promoted: *promoted,
+                from: *from,
+                to: *to,
+            },
+            ParsedTeamFeedEventText::PlayerPositionsSwapped { swap } => {
+                ParsedTeamFeedEventText::PlayerPositionsSwapped {
+                    swap: swap.map_text(f),
+                }
+            }
+            ParsedTeamFeedEventText::PlayerContained {
+                contained_player_name,
+                container_player_name,
+            } => ParsedTeamFeedEventText::PlayerContained {
+                contained_player_name: f(contained_player_name),
+                container_player_name: f(container_player_name),
+            },
+            ParsedTeamFeedEventText::PlayerGrow { grow } => ParsedTeamFeedEventText::PlayerGrow {
+                grow: grow.map_text(f),
+            },
+            ParsedTeamFeedEventText::AttributeSet { set } => {
+                ParsedTeamFeedEventText::AttributeSet {
+                    set: set.map_text(f),
+                }
+            }
+            ParsedTeamFeedEventText::ItemModification { modification } => {
+                ParsedTeamFeedEventText::ItemModification {
+                    modification: modification.map_text(f),
+                }
+            }
+            ParsedTeamFeedEventText::Callup {
+                lesser_league_team,
+                greater_league_team,
+                slot,
+                promoted_player_name,
+                demoted_player_name,
+            } => ParsedTeamFeedEventText::Callup {
+                lesser_league_team: lesser_league_team.map_text(f),
+                greater_league_team: greater_league_team.map_text(f),
+                slot: *slot,
+                promoted_player_name: f(promoted_player_name),
+                demoted_player_name: f(demoted_player_name),
+            },
+            ParsedTeamFeedEventText::GreaterAugment {
+                team,
+                greater_augment,
+            } => ParsedTeamFeedEventText::GreaterAugment {
+                team: team.map_text(f),
+                greater_augment: greater_augment.clone(),
+            },
+            ParsedTeamFeedEventText::PlayerGrewInEfflorescence {
+                player_name,
+                growths,
+            } => ParsedTeamFeedEventText::PlayerGrewInEfflorescence {
+                player_name: f(player_name),
+                growths: *growths,
+            },
+            ParsedTeamFeedEventText::PlayerEffloresce { player_name } => {
+                ParsedTeamFeedEventText::PlayerEffloresce {
+                    player_name: f(player_name),
+                }
+            }
+            ParsedTeamFeedEventText::ClaimedLinealBelt {
+                team,
+                old_belt_holder_team,
+            } => ParsedTeamFeedEventText::ClaimedLinealBelt {
+                team: team.map_text(f),
+                old_belt_holder_team: old_belt_holder_team.map_text(f),
+            },
+            ParsedTeamFeedEventText::LostLinealBelt {
+                team,
+                new_belt_holder_team,
+            } => ParsedTeamFeedEventText::LostLinealBelt {
+                team: team.map_text(f),
+                new_belt_holder_team: new_belt_holder_team.map_text(f),
+            },
+            ParsedTeamFeedEventText::SeasonBegan { season } => {
+                ParsedTeamFeedEventText::SeasonBegan { season: *season }
+            }
+            ParsedTeamFeedEventText::PostseasonBegan => ParsedTeamFeedEventText::PostseasonBegan,
+            ParsedTeamFeedEventText::AllStarSelection { player, team } => {
+                ParsedTeamFeedEventText::AllStarSelection {
+                    player: f(player),
+                    team: f(team),
+                }
+            }
+            ParsedTeamFeedEventText::EnteredSuperstarGame { player } => {
+                ParsedTeamFeedEventText::EnteredSuperstarGame { player: f(player) }
+            }
+            ParsedTeamFeedEventText::Released { team } => {
+                ParsedTeamFeedEventText::Released { team: f(team) }
+            }
+            ParsedTeamFeedEventText::Retirement { previous, new } => {
+                ParsedTeamFeedEventText::Retirement {
+                    previous: f(previous),
+                    new: new.as_ref().map(&mut *f),
+                }
+            }
+        }
+    }
+}
+
+impl<S: AsRef<str>> ParsedTeamFeedEventText<S> {
+    /// Copies every string into `interner`, so strings that repeat across events share one
+    /// allocation.
+    pub fn intern(&self, interner: &mut StringInterner) -> ParsedTeamFeedEventText<Arc<str>> {
+        self.map_text(&mut |s| interner.intern(s.as_ref()))
+    }
+}
+
+impl<S: Debug> ParsedTeamFeedEventText<S> {
+    /// A key that sorts parsed events into the same order no matter what order they came in:
+    /// the variant name, then the variant's `Debug` output. Building it formats the whole event,
+    /// so sort with `events.sort_by_cached_key(ParsedTeamFeedEventText::sort_key)`.
+    pub fn sort_key(&self) -> (&'static str, String) {
+        (self.into(), format!("{self:?}"))
+    }
+}
+
 impl<S: Display> ParsedTeamFeedEventText<S> {
+    /// A short description for logs and debugging, e.g. `GameResult: 🦖 Peoria 2 - 4 📮 Akron`.
+    /// Unlike [`Self::unparse`] this doesn't need the event, so it isn't the original text.
+    /// Variants without anything interesting to say are just their name.
+    pub fn summary(&self) -> String {
+        let name: &'static str = self.into();
+        let details = match self {
+            ParsedTeamFeedEventText::ParseError { error, .. } => error.to_string(),
+            ParsedTeamFeedEventText::GameResult {
+                home_team,
+                away_team,
+                home_score,
+                away_score,
+                annotation,
+                ..
+            } => match annotation {
+                Some(annotation) => {
+                    format!("{away_team} {away_score} - {home_score} {home_team} {annotation}")
+                }
+                None => format!("{away_team} {away_score} - {home_score} {home_team}"),
+            },
+            ParsedTeamFeedEventText::Delivery { delivery }
+            | ParsedTeamFeedEventText::Shipment { delivery }
+            | ParsedTeamFeedEventText::SpecialDelivery { delivery } => {
+                format!("{} {}", delivery.player, delivery.item)
+            }
+            ParsedTeamFeedEventText::DonatedToLottery {
+                amount,
+                league_name,
+                ..
+            }
+            | ParsedTeamFeedEventText::WonLottery {
+                amount,
+                league_name,
+            } => {
+                format!("{amount} 🪙 {league_name}")
+            }
+            ParsedTeamFeedEventText::Payout {
+                recipient,
+                amount,
+                currency,
+                ..
+            } => format!("{recipient} {amount} {currency}"),
+            ParsedTeamFeedEventText::Enchantment {
+                team_name,
+                item,
+                enchants,
+                ..
+            } => {
+                let bonuses = enchants
+                    .iter()
+                    .map(|(amount, attribute)| format!("+{amount} {attribute}"))
+                    .join(", ");
+                format!("{team_name}'s {item} {bonuses}")
+            }
+            ParsedTeamFeedEventText::CompensatoryEnchantments { enchantments } => enchantments
+                .iter()
+                .map(|enchantment| {
+                    let bonuses = enchantment
+                        .enchants
+                        .iter()
+                        .map(|(amount, attribute)| format!("+{amount} {attribute}"))
+                        .join(", ");
+                    format!(
+                        "{}'s {} {bonuses}",
+                        enchantment.player_name, enchantment.item
+                    )
+                })
+                .join("; "),
+            ParsedTeamFeedEventText::AttributeChanges { changes } => changes
+                .iter()
+                .map(|change| {
+                    format!(
+                        "{} {:+} {}",
+                        change.player_name, change.amount, change.attribute
+                    )
+                })
+                .join(", "),
+            ParsedTeamFeedEventText::AttributeOperations { operations } => operations
+                .iter()
+                .map(|operation| match operation {
+                    AttributeOperation::Gain(change) => {
+                        format!(
+                            "{} {:+} {}",
+                            change.player_name, change.amount, change.attribute
+                        )
+                    }
+                    AttributeOperation::Loss(change) => {
+                        format!(
+                            "{} -{} {}",
+                            change.player_name, change.amount, change.attribute
+                        )
+                    }
+                    AttributeOperation::Equal {
+                        player_name,
+                        changing_attribute,
+                        value_attribute,
+                        ..
+                    } => format!("{player_name} {changing_attribute} = {value_attribute}"),
+                })
+                .join(", "),
+            ParsedTeamFeedEventText::TeamAttributeChange {
+                target,
+                amount,
+                attribute,
+            } => {
+                format!("{target} {amount:+} {attribute}")
+            }
+            ParsedTeamFeedEventText::Recomposed { previous, new } => format!("{previous} -> {new}"),
+            ParsedTeamFeedEventText::Modification {
+                team_name,
+                modification,
+                additional_modifications,
+                ..
+            } => format!(
+                "{team_name} {}",
+                std::iter::once(modification)
+                    .chain(additional_modifications)
+                    .join(", ")
+            ),
+            ParsedTeamFeedEventText::RosterMove {
+                player_name,
+                from,
+                to,
+                ..
+            } => format!("{player_name} {from} -> {to}"),
+            ParsedTeamFeedEventText::SeasonBegan { season } => season.to_string(),
+            ParsedTeamFeedEventText::Retirement { previous, .. } => previous.to_string(),
+            ParsedTeamFeedEventText::Injury { player_name }
+            | ParsedTeamFeedEventText::Recovery { player_name } => player_name.to_string(),
+            ParsedTeamFeedEventText::AllStarSelection { player, team } => {
+                format!("{player} ({team})")
+            }
+            ParsedTeamFeedEventText::EnteredSuperstarGame { player } => player.to_string(),
+            _ => return name.to_string(),
+        };
+        format!("{name}: {details}")
+    }
+
+    /// Builds the feed event this would have been parsed from, for making test data. The text
+    /// comes from [`Self::unparse`] and the type from [`Self::feed_event_type`] (or the
+    /// template's, when that's None). Everything else is copied from `template`.
+    pub fn into_feed_event(&self, template: &FeedEvent) -> FeedEvent {
+        let mut event = template.clone();
+        if let Some(event_type) = self.feed_event_type() {
+            event.event_type = Ok(event_type);
+        }
+        // Some unparsing depends on the event type, so this has to come after setting it
+        event.text = self.unparse(&event);
+        event
+    }
+
     pub fn unparse(&self, event: &FeedEvent) -> String {
         match self {
             ParsedTeamFeedEventText::ParseError { error: _, text } => text.to_string(),
-            ParsedTeamFeedEventText::GameResult { home_team, away_team, home_score, away_score } => {
-                format!("{} vs. {} - FINAL {}-{}", away_team, home_team, away_score, home_score)
+            ParsedTeamFeedEventText::GameResult { home_team, away_team, home_score, away_score, annotation, score_separator } => {
+                let annotation = annotation.map(|annotation| format!(" {annotation}")).unwrap_or_default();
+                let separator = score_separator.map_or("-", ScoreSeparator::as_str);
+                format!("{} vs. {} - FINAL {}{separator}{}{annotation}", away_team, home_team, away_score, home_score)
             }
             ParsedTeamFeedEventText::Delivery { delivery } => delivery.unparse(event, "Delivery"),
             ParsedTeamFeedEventText::Shipment { delivery } => delivery.unparse(event, "Shipment"),
@@ -226,6 +1318,9 @@ impl<S: Display> ParsedTeamFeedEventText<S> {
 
                 format!("{team} are Prosperous! They {verb} {income} 🪙.")
             },
+            ParsedTeamFeedEventText::Payout { recipient, amount, thousands_separators, currency } => {
+                format!("{recipient} received a payout of {} {currency}.", unparse_thousands_separated(*amount, *thousands_separators))
+            }
             ParsedTeamFeedEventText::DonatedToLottery { team_name, amount, league_name } => {
                 format!("The {team_name} donated {amount} 🪙 to the {league_name} Lottery.")
             }
@@ -235,12 +1330,39 @@ impl<S: Display> ParsedTeamFeedEventText<S> {
             ParsedTeamFeedEventText::FallingStarOutcome { player_name, outcome } => {
                 outcome.unparse(event, player_name)
             }
+            ParsedTeamFeedEventText::AllStarSelection { player, team } => {
+                format!("{player} was selected to represent the {team} in the Superstar Game.")
+            }
+            ParsedTeamFeedEventText::EnteredSuperstarGame { player } => {
+                format!("{player} entered the Superstar Game!")
+            }
+            // Both of these give back the guessed wording, see `injury` in shared
+            ParsedTeamFeedEventText::Injury { player_name } => {
+                if event.after(Breakpoints::Season5TenseChange) {
+                    format!("{player_name} is injured!")
+                } else {
+                    format!("{player_name} was injured!")
+                }
+            }
+            ParsedTeamFeedEventText::Recovery { player_name } => {
+                if event.after(Breakpoints::Season5TenseChange) {
+                    format!("{player_name} is fully healed!")
+                } else {
+                    format!("{player_name} recovered from their injury!")
+                }
+            }
             ParsedTeamFeedEventText::AttributeChanges { changes } => {
                 changes
                     .iter()
-                    .map(|change| format!("{} gained +{} {}.", change.player_name, change.amount, change.attribute))
+                    .map(AttributeChange::unparse_gain)
                     .join(" ")
             },
+            ParsedTeamFeedEventText::TeamAttributeChange { target, amount, attribute } => {
+                format!("{target} gained +{amount} {attribute}.")
+            },
+            ParsedTeamFeedEventText::AttributeOperations { operations } => {
+                operations.iter().join(" ")
+            },
             ParsedTeamFeedEventText::MassAttributeEquals { players, changing_attribute, value_attribute } => {
                 if Breakpoints::Season3.after(event.season as u32, event.day.as_ref().copied().ok(), None) {
                     let intro = format!("Batters' {changing_attribute} was set to their {value_attribute}. Lineup:");
@@ -274,36 +1396,37 @@ impl<S: Display> ParsedTeamFeedEventText<S> {
             ParsedTeamFeedEventText::TakeTheMound { to_mound_team, to_lineup_team } => format!("{to_mound_team} was moved to the mound. {to_lineup_team} was sent to the lineup."),
             ParsedTeamFeedEventText::TakeThePlate { to_plate_team, from_lineup_team } => format!("{to_plate_team} was sent to the plate. {from_lineup_team} was pulled from the lineup."),
             ParsedTeamFeedEventText::SwapPlaces { team_one, team_two } => format!("{team_one} swapped places with {team_two}."),
-            ParsedTeamFeedEventText::Enchantment { team_name, item, amount, attribute, enchant_two, compensatory } => {
-                        if event.before(Breakpoints::Season1EnchantmentChange) {
-                            if enchant_two.is_some() {
-                                tracing::error!("Season 1 enchantment had two enchants");
+            ParsedTeamFeedEventText::Enchantment { team_name, item, article, enchants, compensatory, also } => {
+                        if event.before(Breakpoints::season(2)) {
+                            if enchants.len() > 1 {
+                                tracing::error!("Season 1 enchantment had multiple enchants");
                             }
                             if *compensatory {
                                 tracing::error!("Season 1 enchantment was compensatory")
                             }
-                            format!("{team_name}'s {item} was enchanted with +{amount} to {attribute}.")
-                        } else if event.before(Breakpoints::season(2)) {
-                            if enchant_two.is_some() {
-                                tracing::error!("Season 1 enchantment had two enchants");
-                            }
-                            if *compensatory {
-                                tracing::error!("Season 1 enchantment was compensatory")
-                            }
-                            format!("The Item Enchantment was a success! {team_name}'s {item} gained a +{amount} {attribute} bonus.")
+                        }
+
+                        if event.before(Breakpoints::Season1EnchantmentChange) {
+                            let bonuses = enchants.iter().map(|(amount, attribute)| format!("+{amount} to {attribute}")).join(" and ");
+                            format!("{team_name}'s {item} was enchanted with {bonuses}.")
                         } else {
-                            let enchant_type = compensatory.then_some("Compensatory").unwrap_or("Item");
-                            match enchant_two {
-                                Some((amount_two, attribute_two)) => format!("The {enchant_type} Enchantment was a success! {team_name}'s {item} was enchanted with +{amount} {attribute} and +{amount_two} {attribute_two}."),
-                                None =>  format!("The {enchant_type} Enchantment was a success! {team_name}'s {item} gained a +{amount} {attribute} bonus.")
-                            }
+                            unparse_enchantment(*compensatory, team_name, item, article.as_ref(), enchants, *also)
                         }
                     },
+            ParsedTeamFeedEventText::SeasonBegan { season } => format!("Season {season} has begun."),
+            ParsedTeamFeedEventText::PostseasonBegan => "The Postseason begins.".to_string(),
+            ParsedTeamFeedEventText::CompensatoryEnchantments { enchantments } => {
+                enchantments.iter().map(ItemEnchantment::unparse_compensatory).join(" ")
+            },
+            ParsedTeamFeedEventText::Disenchantment { team_name, item, amount, attribute } => {
+                format!("{team_name}'s {item} lost its +{amount} {attribute} bonus.")
+            },
             ParsedTeamFeedEventText::Released { team } => format!("Released by the {team}."),
-            ParsedTeamFeedEventText::Modification { team_name, lost_modification, modification } => {
+            ParsedTeamFeedEventText::Modification { team_name, lost_modification, modification, additional_modifications } => {
+                let modifications = unparse_modification_list(modification, additional_modifications);
                 match lost_modification {
-                    Some(lost_modification) => format!("{team_name} lost the {lost_modification} Modification. {team_name} gained the {modification} Modification."),
-                    None => format!("{team_name} gained the {modification} Modification.")
+                    Some(lost_modification) => format!("{team_name} lost the {lost_modification} Modification. {team_name} gained {modifications}"),
+                    None => format!("{team_name} gained {modifications}")
                 }
             },
             ParsedTeamFeedEventText::Retirement { previous, new } => {
@@ -326,6 +1449,10 @@ impl<S: Display> ParsedTeamFeedEventText<S> {
             ParsedTeamFeedEventText::PlayerRelegated { player_name } => {
                 format!("🧳 {player_name} was relegated to the Even Lesser League.")
             },
+            ParsedTeamFeedEventText::RosterMove { team_emoji, player_name, promoted, from, to } => {
+                let verb = if *promoted { "promoted" } else { "demoted" };
+                format!("{team_emoji} {player_name} was {verb} from the {from} to the {to}.")
+            },
             ParsedTeamFeedEventText::PlayerPositionsSwapped { swap } => {
                 format!("{swap}")
             },
@@ -338,6 +1465,8 @@ impl<S: Display> ParsedTeamFeedEventText<S> {
             ParsedTeamFeedEventText::PlayerGrow { grow } => {
                 format!("{grow}")
             },
+            ParsedTeamFeedEventText::AttributeSet { set } => set.to_string(),
+            ParsedTeamFeedEventText::ItemModification { modification } => modification.to_string(),
             ParsedTeamFeedEventText::Callup { lesser_league_team, greater_league_team, slot, promoted_player_name, demoted_player_name } => {
                 format!(
                     "{lesser_league_team} {slot} {promoted_player_name} was called up to replace \
@@ -368,3 +1497,557 @@ impl<S: Display> ParsedTeamFeedEventText<S> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use crate::{
+        enums::FeedEventSource,
+        parsed_event::EmojiTeam,
+        utils::{assert_feeds_parse_or_allowlisted, feed_event},
+    };
+
+    use super::ParsedTeamFeedEventText;
+
+    #[test]
+    fn raw_team_feeds_parse() -> Result<(), Box<dyn std::error::Error>> {
+        assert_feeds_parse_or_allowlisted(
+            Path::new("test_data/raw/team_feed"),
+            FeedEventSource::Team,
+        )
+    }
+
+    #[test]
+    fn ndjson_stream() {
+        let good =
+            serde_json::to_string(&feed_event(3, 40, "season", "Season 3 has begun.")).unwrap();
+        let dump = format!("{good}\n\n{{\"text\": 5}}\n");
+
+        let results: Vec<_> = super::parse_team_feed_events_ndjson(dump.as_bytes()).collect();
+        assert_eq!(results.len(), 2);
+        assert_eq!(
+            results[0].as_ref().unwrap(),
+            &super::ParsedTeamFeedEventText::SeasonBegan { season: 3 }
+        );
+        assert!(matches!(
+            results[1],
+            Err(crate::feed_event::FeedNdjsonError::Deserialize { line: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn owned_outlives_event() -> Result<(), Box<dyn std::error::Error>> {
+        let event = feed_event(4, 12, "game", "🦀 Crabs vs. 🐝 Bees - FINAL 3-7");
+        let parsed = super::parse_team_feed_event_owned(&event);
+        let text = event.text.clone();
+        drop(event);
+
+        let super::ParsedTeamFeedEventText::GameResult {
+            home_team,
+            away_team,
+            ..
+        } = &parsed
+        else {
+            panic!("expected a game result, got {parsed:?}");
+        };
+        assert_eq!(away_team.name, "Crabs");
+        assert_eq!(home_team.emoji, "🐝");
+        assert_eq!(format!("{away_team} vs. {home_team} - FINAL 3-7"), text);
+        Ok(())
+    }
+
+    #[test]
+    fn interned_names_share_storage() -> Result<(), Box<dyn std::error::Error>> {
+        let feed = [
+            "🦀 Crabs vs. 🐝 Bees - FINAL 3-7",
+            "🐝 Bees vs. 🦀 Crabs - FINAL 1-0",
+        ]
+        .into_iter()
+        .map(|text| feed_event(4, 12, "game", text))
+        .collect::<Vec<_>>();
+
+        let (parsed, interner) = super::parse_team_feed_events_interned(&feed);
+        // Two names and two emojis
+        assert_eq!(interner.len(), 4);
+
+        let [super::ParsedTeamFeedEventText::GameResult {
+            away_team: first, ..
+        }, super::ParsedTeamFeedEventText::GameResult {
+            home_team: second, ..
+        }] = parsed.as_slice()
+        else {
+            panic!("expected two game results, got {parsed:?}");
+        };
+        assert_eq!(&*first.name, "Crabs");
+        assert!(std::sync::Arc::ptr_eq(&first.name, &second.name));
+        assert!(std::sync::Arc::ptr_eq(&first.emoji, &second.emoji));
+
+        for (event, interned) in feed.iter().zip(&parsed) {
+            assert_eq!(interned.unparse(event), event.text);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn raw_team_feeds_owned_match_borrowed() -> Result<(), Box<dyn std::error::Error>> {
+        for entry in Path::new("test_data/raw/team_feed").read_dir()? {
+            let feed: super::TeamFeed =
+                serde_json::from_str(&std::fs::read_to_string(entry?.path())?)?;
+            for event in &feed.feed {
+                assert_eq!(
+                    super::parse_team_feed_event_owned(event).unparse(event),
+                    super::parse_team_feed_event(event).unparse(event),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn iterator_filters() {
+        use super::FeedEventIteratorExt;
+
+        let feed = [
+            ("game", "🦀 Crabs vs. 🐝 Bees - FINAL 3-7"),
+            ("augment", "The Item Enchantment was a success! Yumi Pratt's Sharp Cap gained a +12 Contact bonus."),
+            ("augment", "Yumi Pratt gained +5 Contact. Kelly Vieira gained +3 Aiming."),
+            ("season", "Season 4 has begun."),
+            ("game", "🐝 Bees vs. 🦀 Crabs - FINAL 1-0"),
+        ]
+        .into_iter()
+        .map(|(event_type, text)| feed_event(4, 12, event_type, text))
+        .collect::<Vec<_>>();
+
+        assert_eq!(feed.iter().parsed().count(), 5);
+
+        let scores = feed
+            .iter()
+            .game_results()
+            .map(|(away, home, away_score, home_score)| {
+                (away.name, home.name, away_score, home_score)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            scores,
+            vec![("Crabs", "Bees", 3, 7), ("Bees", "Crabs", 1, 0)]
+        );
+
+        let changes = feed.iter().attribute_changes().collect::<Vec<_>>();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[1].player_name, "Kelly Vieira");
+        assert_eq!(feed.iter().batting_changes().count(), 2);
+        assert_eq!(feed.iter().pitching_changes().count(), 0);
+
+        let enchantments = feed.iter().enchantments().collect::<Vec<_>>();
+        assert_eq!(enchantments.len(), 1);
+        assert_eq!(enchantments[0].0, "Yumi Pratt");
+        assert_eq!(
+            enchantments[0].2,
+            vec![(12, crate::enums::Attribute::Contact)]
+        );
+    }
+
+    #[test]
+    fn semantic_eq_ignores_wording() {
+        use super::ParsedTeamFeedEventText;
+        use crate::{
+            enums::{Attribute, ItemName},
+            feed_event::EmojilessItem,
+        };
+
+        let enchantment = |article, also| ParsedTeamFeedEventText::Enchantment {
+            team_name: "Yumi Pratt",
+            item: EmojilessItem {
+                prefix: None,
+                item: ItemName::Cap,
+                suffix: None,
+            },
+            article,
+            enchants: vec![(12, Attribute::Contact), (3, Attribute::Aiming)],
+            compensatory: false,
+            also,
+        };
+
+        let a = enchantment(Some("a"), false);
+        let an = enchantment(Some("an"), true);
+        assert_ne!(a, an);
+        assert!(a.semantic_eq(&an));
+        assert!(a.semantic_eq(&enchantment(None, false)));
+
+        let ParsedTeamFeedEventText::Enchantment {
+            team_name, item, ..
+        } = a.clone()
+        else {
+            unreachable!()
+        };
+        let other_bonus = ParsedTeamFeedEventText::Enchantment {
+            team_name,
+            item,
+            article: Some("a"),
+            enchants: vec![(12, Attribute::Contact)],
+            compensatory: false,
+            also: false,
+        };
+        assert!(!a.semantic_eq(&other_bonus));
+    }
+
+    #[test]
+    fn attribute_changes_are_borrowed() {
+        use super::ParsedTeamFeedEventText;
+        use crate::{enums::Attribute, feed_event::AttributeChange};
+
+        let parsed = ParsedTeamFeedEventText::AttributeChanges {
+            changes: vec![AttributeChange {
+                player_name: String::from("Yumi Pratt"),
+                amount: 5,
+                attribute: Attribute::Contact,
+                multiplier: None,
+            }],
+        };
+
+        let changes = parsed
+            .attribute_changes()
+            .expect("should have attribute changes");
+        assert_eq!(changes[0].player_name, "Yumi Pratt");
+        let ParsedTeamFeedEventText::AttributeChanges { changes: owned } = &parsed else {
+            unreachable!()
+        };
+        assert!(std::ptr::eq(changes, owned.as_slice()));
+
+        assert_eq!(
+            ParsedTeamFeedEventText::<String>::PostseasonBegan.attribute_changes(),
+            None
+        );
+    }
+
+    #[test]
+    fn net_attribute_changes() {
+        use crate::enums::Attribute;
+
+        let feed = [
+            ("augment", "Yumi Pratt gained +5 Contact. Kelly Vieira gained +3 Aiming."),
+            ("augment", "The Item Enchantment was a success! Yumi Pratt's Sharp Cap gained a +12 Contact bonus."),
+            ("augment", "Kelly Vieira gained +1 Aiming. Yumi Pratt lost 2 Contact."),
+            ("season", "Season 4 has begun."),
+            ("augment", "This isn't an augment at all."),
+        ]
+        .into_iter()
+        .map(|(event_type, text)| feed_event(4, 12, event_type, text))
+        .collect::<Vec<_>>();
+        let parsed = feed
+            .iter()
+            .map(super::parse_team_feed_event)
+            .collect::<Vec<_>>();
+
+        let totals = super::net_attribute_changes(&parsed);
+        assert_eq!(totals.len(), 2);
+        assert_eq!(totals[&("Yumi Pratt", Attribute::Contact)], 15);
+        assert_eq!(totals[&("Kelly Vieira", Attribute::Aiming)], 4);
+    }
+
+    #[test]
+    fn attribute_changes_by_category() {
+        use super::FeedEventIteratorExt;
+
+        let feed = [feed_event(
+            4,
+            12,
+            "augment",
+            "Yumi Pratt gained +5 Contact. Kelly Vieira gained +3 Velocity. Nancy Bright gained +50 Awareness. Ben Ito gained +2 Speed. Ben Ito gained +1 Luck.",
+        )];
+
+        let names = |changes: Vec<crate::feed_event::AttributeChange<&str>>| {
+            changes
+                .into_iter()
+                .map(|change| change.player_name.to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            names(feed.iter().batting_changes().collect()),
+            vec!["Yumi Pratt"]
+        );
+        assert_eq!(
+            names(feed.iter().pitching_changes().collect()),
+            vec!["Kelly Vieira"]
+        );
+        assert_eq!(
+            names(feed.iter().defense_changes().collect()),
+            vec!["Nancy Bright"]
+        );
+        assert_eq!(
+            names(feed.iter().baserunning_changes().collect()),
+            vec!["Ben Ito"]
+        );
+        // Luck has no category, so it only shows up unfiltered
+        assert_eq!(feed.iter().attribute_changes().count(), 5);
+    }
+
+    #[test]
+    fn summaries() {
+        let summaries = [
+            ("game", "🦖 Peoria vs. 📮 Akron - FINAL 2-4"),
+            ("augment", "Nancy Bright gained +50 Awareness. Kelly Vieira gained +3 Aiming."),
+            ("augment", "The Item Enchantment was a success! Yumi Pratt's Sharp Cap gained a +12 Contact bonus."),
+            ("season", "Season 4 has begun."),
+            ("season", "The Postseason begins."),
+            ("game", "Something nobody has seen before."),
+        ]
+        .into_iter()
+        .map(|(event_type, text)| {
+            super::parse_team_feed_event(&feed_event(4, 12, event_type, text)).summary()
+        })
+        .collect::<Vec<_>>();
+
+        assert_eq!(summaries[0], "GameResult: 🦖 Peoria 2 - 4 📮 Akron");
+        assert_eq!(
+            summaries[1],
+            "AttributeChanges: Nancy Bright +50 Awareness, Kelly Vieira +3 Aiming"
+        );
+        assert_eq!(
+            summaries[2],
+            "Enchantment: Yumi Pratt's Sharp Cap +12 Contact"
+        );
+        assert_eq!(summaries[3], "SeasonBegan: 4");
+        assert_eq!(summaries[4], "PostseasonBegan");
+        assert!(summaries[5].starts_with("ParseError: "));
+    }
+
+    #[test]
+    fn delivery_kinds() {
+        use crate::feed_event::DeliveryKind;
+
+        let cases = [
+            (
+                "Tyler Kerr received a 💍 Ring Delivery.",
+                DeliveryKind::Delivery,
+            ),
+            (
+                "Tara McBride received a 🧢 Selfless Cap Shipment.",
+                DeliveryKind::Shipment,
+            ),
+            (
+                "Jeffery Noel received a 👟 Stalwart Sneakers of Reflexes Special Delivery.",
+                DeliveryKind::SpecialDelivery,
+            ),
+        ];
+        for (text, kind) in cases {
+            let event = feed_event(4, 12, "game", text);
+            let parsed = super::parse_team_feed_event(&event);
+            let (parsed_kind, delivery) = parsed.delivery().expect("should be a delivery");
+            assert_eq!(parsed_kind, kind);
+            assert!(text.starts_with(delivery.player));
+            assert!(text.ends_with(&format!(" {kind}.")));
+        }
+    }
+
+    #[test]
+    fn registry_takes_precedence() {
+        use super::ParsedTeamFeedEventText;
+        use crate::{enums::FeedEventType, feed_event::ParserRegistry};
+
+        let mut registry = ParserRegistry::new();
+        registry.register(
+            FeedEventType::Augment,
+            Box::new(|text: &str| {
+                let season = text
+                    .strip_prefix("The Moon rose over Season ")?
+                    .strip_suffix('.')?;
+                Some(ParsedTeamFeedEventText::SeasonBegan {
+                    season: season.parse().ok()?,
+                })
+            }),
+        );
+        // Would match everything, but only for a type that isn't being parsed here
+        registry.register(
+            FeedEventType::Game,
+            Box::new(|_| Some(ParsedTeamFeedEventText::PostseasonBegan)),
+        );
+
+        let novel = feed_event(4, 12, "augment", "The Moon rose over Season 4.");
+        assert!(matches!(
+            super::parse_team_feed_event(&novel),
+            ParsedTeamFeedEventText::ParseError { .. }
+        ));
+        assert_eq!(
+            super::parse_team_feed_event_with_registry(&novel, &registry),
+            ParsedTeamFeedEventText::SeasonBegan { season: 4 }
+        );
+
+        // Falls back to the built-in parsers when no custom one matches
+        let known = feed_event(4, 12, "augment", "Yumi Pratt gained +5 Contact.");
+        assert_eq!(
+            super::parse_team_feed_event_with_registry(&known, &registry),
+            super::parse_team_feed_event_owned(&known)
+        );
+    }
+
+    #[test]
+    fn sorts_deterministically() {
+        use super::ParsedTeamFeedEventText;
+        use crate::parsed_event::EmojiTeam;
+
+        let game_result = |away_score, home_score| ParsedTeamFeedEventText::GameResult {
+            home_team: EmojiTeam {
+                emoji: "🐝",
+                name: "Bees",
+            },
+            away_team: EmojiTeam {
+                emoji: "🦀",
+                name: "Crabs",
+            },
+            home_score,
+            away_score,
+            annotation: None,
+            score_separator: None,
+        };
+        let sorted = vec![
+            ParsedTeamFeedEventText::SeasonBegan { season: 8 },
+            game_result(3, 7),
+            ParsedTeamFeedEventText::PostseasonBegan,
+            ParsedTeamFeedEventText::SeasonBegan { season: 9 },
+            game_result(3, 8),
+            ParsedTeamFeedEventText::NameChanged,
+        ];
+
+        let mut forwards = sorted.clone();
+        forwards.sort_by_cached_key(ParsedTeamFeedEventText::sort_key);
+        let mut backwards = sorted.into_iter().rev().collect::<Vec<_>>();
+        backwards.sort_by_cached_key(ParsedTeamFeedEventText::sort_key);
+        assert_eq!(forwards, backwards);
+        assert_eq!(
+            forwards,
+            vec![
+                game_result(3, 7),
+                game_result(3, 8),
+                ParsedTeamFeedEventText::NameChanged,
+                ParsedTeamFeedEventText::PostseasonBegan,
+                ParsedTeamFeedEventText::SeasonBegan { season: 8 },
+                ParsedTeamFeedEventText::SeasonBegan { season: 9 },
+            ]
+        );
+    }
+
+    #[test]
+    fn into_feed_event() {
+        let template = feed_event(4, 12, "season", "");
+
+        for (event_type, text) in [
+            ("game", "🦀 Crabs vs. 🐝 Bees - FINAL 3-7"),
+            ("augment", "Yumi Pratt gained +3 Contact."),
+            ("lottery", "Won 100 🪙 from the Clean League Lottery!"),
+        ] {
+            let event = feed_event(4, 12, event_type, text);
+            let rebuilt = super::parse_team_feed_event(&event).into_feed_event(&template);
+            assert_eq!(rebuilt.text, event.text);
+            assert_eq!(rebuilt.event_type, event.event_type);
+            assert_eq!(rebuilt.emoji, template.emoji);
+        }
+    }
+
+    #[cfg(feature = "bumpalo")]
+    #[test]
+    fn parse_into_arena() {
+        use super::ParsedTeamFeedEventText;
+
+        let arena = bumpalo::Bump::new();
+        let parsed = {
+            let events = [
+                ("game", "🦀 Crabs vs. 🐝 Bees - FINAL 3-7"),
+                ("season", "Season 3 has begun."),
+                ("game", "Something nobody has seen before."),
+            ]
+            .into_iter()
+            .map(|(event_type, text)| feed_event(4, 12, event_type, text))
+            .collect::<Vec<_>>();
+
+            events
+                .iter()
+                .map(|event| super::parse_team_feed_event_in(event, &arena))
+                .collect::<Vec<_>>()
+        };
+
+        // The events are gone by now, so these all point into the arena
+        assert!(matches!(
+            &parsed[0],
+            ParsedTeamFeedEventText::GameResult { away_team, home_score: 7, .. }
+                if away_team.name == "Crabs"
+        ));
+        assert_eq!(
+            parsed[1],
+            ParsedTeamFeedEventText::SeasonBegan { season: 3 }
+        );
+        assert!(matches!(
+            parsed[2],
+            ParsedTeamFeedEventText::ParseError {
+                text: "Something nobody has seen before.",
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn reconcile_names() {
+        let team = |emoji: &str, name: &str| EmojiTeam {
+            emoji: emoji.to_string(),
+            name: name.to_string(),
+        };
+        let game_result = |away_team, home_team| ParsedTeamFeedEventText::GameResult {
+            away_team,
+            home_team,
+            away_score: 2,
+            home_score: 4,
+            annotation: None,
+            score_separator: None,
+        };
+
+        let known_teams = [
+            team("🦖", "Peoria Monster Monster Monster"),
+            team("📮", "Akron Postmasters"),
+            // The same name once the spaces are gone
+            team("🐝", "Bee Sting"),
+            team("🐝", "BeeSting"),
+        ];
+
+        let mut parsed = game_result(
+            team("🦖", "PeoriaMonsterMonsterMonster"),
+            team("📮", "Akron Postmasters"),
+        );
+        parsed.reconcile_names(&known_teams);
+        assert_eq!(
+            parsed,
+            game_result(
+                team("🦖", "Peoria Monster Monster Monster"),
+                team("📮", "Akron Postmasters"),
+            )
+        );
+
+        // No unique match, so these are left alone
+        let mut parsed = game_result(team("🐝", "BeeSting"), team("🦀", "CrabShack"));
+        parsed.reconcile_names(&known_teams);
+        assert_eq!(
+            parsed,
+            game_result(team("🐝", "BeeSting"), team("🦀", "CrabShack"))
+        );
+    }
+
+    #[test]
+    fn wildcard_match() {
+        let events = [
+            ("game", "🦀 Crabs vs. 🐝 Bees - FINAL 3-7"),
+            ("season", "Season 4 has begun."),
+            ("game", "Something nobody has seen before."),
+        ]
+        .map(|(event_type, text)| feed_event(4, 12, event_type, text));
+
+        let kinds = events
+            .iter()
+            .map(|event| match super::parse_team_feed_event(event) {
+                ParsedTeamFeedEventText::GameResult { .. } => "game result",
+                other if other.is_known() => "other",
+                _ => "unknown",
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(kinds, ["game result", "other", "unknown"]);
+    }
+}
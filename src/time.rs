@@ -63,6 +63,38 @@ impl Ord for DayEquivalent {
         }
     }
 }
+/// Days are ordered by when they happen in the season. Named days that share a slot in the season
+/// are ordered by variant so the order is total.
+impl PartialOrd for Day {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Day {
+    fn cmp(&self, other: &Self) -> Ordering {
+        fn tiebreak(day: Day) -> (u8, u16) {
+            match day {
+                Day::Day(day) => (0, day),
+                Day::Preseason => (1, 0),
+                Day::SuperstarBreak => (2, 0),
+                Day::SuperstarGame => (3, 0),
+                Day::SuperstarDay(day) => (4, day as u16),
+                Day::PostseasonPreview => (5, 0),
+                Day::PostseasonRound(round) => (6, round as u16),
+                Day::Election => (7, 0),
+                Day::Holiday => (8, 0),
+                Day::Event => (9, 0),
+                Day::SpecialEvent => (10, 0),
+                Day::Offseason => (11, 0),
+            }
+        }
+
+        DayEquivalent::new(0, *self)
+            .cmp(&DayEquivalent::new(0, *other))
+            .then_with(|| tiebreak(*self).cmp(&tiebreak(*other)))
+    }
+}
+
 impl DayEquivalent {
     pub fn new(_season: u32, day: Day) -> Self {
         match day {
@@ -73,7 +105,7 @@ impl DayEquivalent {
             },
             Day::SuperstarDay(offset) => DayEquivalent {
                 day: 120,
-                offset: offset + 1,
+                offset: offset.saturating_add(1),
             },
             Day::PostseasonRound(round) => DayEquivalent {
                 day: 254,
@@ -94,11 +126,11 @@ impl DayEquivalent {
             },
             Day::Event => DayEquivalent {
                 day: 255,
-                offset: 2,
+                offset: 3,
             },
             Day::SpecialEvent => DayEquivalent {
                 day: 255,
-                offset: 2,
+                offset: 4,
             },
             Day::SuperstarGame => DayEquivalent {
                 day: 120,
@@ -106,7 +138,7 @@ impl DayEquivalent {
             },
             Day::Offseason => DayEquivalent {
                 day: 255,
-                offset: 2,
+                offset: 5,
             },
         }
     }
@@ -295,4 +327,24 @@ mod test {
         assert!(Breakpoints::S2D169.after(2, Some(Day::Day(200)), Some(5)));
         assert!(Breakpoints::S2D169.after(3, Some(Day::Day(255)), Some(5)));
     }
+
+    #[test]
+    fn named_days_are_ordered() {
+        assert!(Day::Preseason < Day::Day(1));
+        assert!(Day::Day(119) < Day::SuperstarBreak);
+        assert!(Day::SuperstarBreak < Day::Day(121));
+        assert!(Day::Day(240) < Day::PostseasonRound(1));
+        assert!(Day::Election < Day::Holiday);
+        assert!(Day::Holiday < Day::Event);
+        assert!(Day::SpecialEvent < Day::Offseason);
+        assert_ne!(
+            Day::SuperstarGame.cmp(&Day::SuperstarDay(0)),
+            Ordering::Equal
+        );
+
+        assert!(Breakpoints::EternalBattle.after(2, Some(Day::Election), None));
+        assert!(Breakpoints::EternalBattle.before(2, Some(Day::PostseasonRound(3)), None));
+        assert!(Breakpoints::S2D169.after(2, Some(Day::Offseason), None));
+        assert!(Breakpoints::S2D169.before(2, Some(Day::Preseason), None));
+    }
 }
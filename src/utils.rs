@@ -100,6 +100,41 @@ impl<T: Serialize> SerializeAs<Option<T>> for NonStringOrEmptyString {
     }
 }
 
+/// Like [`NonStringOrEmptyString`], but also accepts a number written as a string. There's no
+/// record of which form a number came in, so numeric strings serialize back out as plain numbers.
+pub(crate) struct NumberOrEmptyString;
+
+impl<'de, T: Deserialize<'de> + FromStr> DeserializeAs<'de, Option<T>> for NumberOrEmptyString {
+    fn deserialize_as<D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Helper<T> {
+            Value(T),
+            String(String),
+        }
+
+        match Helper::<T>::deserialize(deserializer)? {
+            Helper::Value(value) => Ok(Some(value)),
+            Helper::String(s) if s.is_empty() => Ok(None),
+            Helper::String(s) => s.parse().map(Some).map_err(|_| {
+                D::Error::custom(format!("expected a number or an empty string, got {s:?}"))
+            }),
+        }
+    }
+}
+
+impl<T: Serialize> SerializeAs<Option<T>> for NumberOrEmptyString {
+    fn serialize_as<S>(source: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        NonStringOrEmptyString::serialize_as(source, serializer)
+    }
+}
+
 /// serde_as converter that expects to always see an empty string. Currently only produces an Option::None value, because it is
 /// intended for use as the second branch of NonStringOrEmptyString.
 struct EmptyString;
@@ -204,16 +239,54 @@ pub type MaybeRecognizedResult<T> = Result<T, NotRecognized>;
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub(crate) struct MaybeRecognizedHelper<T>(PhantomData<T>);
 
+#[cfg(feature = "unrecognized-hook")]
+type UnrecognizedHook = Box<dyn Fn(&serde_json::Value, &'static str)>;
+
+#[cfg(feature = "unrecognized-hook")]
+thread_local! {
+    static UNRECOGNIZED_HOOK: std::cell::RefCell<Option<UnrecognizedHook>> = const { std::cell::RefCell::new(None) };
+}
+
+/// Install a callback that's called (on this thread) with the raw value and the name of the
+/// expected type whenever a value isn't recognized during deserialization. Useful for collecting
+/// a report of new values over a large import. Returns the previously installed hook, if any.
+#[cfg(feature = "unrecognized-hook")]
+pub fn set_unrecognized_hook(
+    hook: impl Fn(&serde_json::Value, &'static str) + 'static,
+) -> Option<UnrecognizedHook> {
+    UNRECOGNIZED_HOOK.with(|h| h.borrow_mut().replace(Box::new(hook)))
+}
+
+/// Remove the callback installed by [`set_unrecognized_hook`], returning it.
+#[cfg(feature = "unrecognized-hook")]
+pub fn clear_unrecognized_hook() -> Option<UnrecognizedHook> {
+    UNRECOGNIZED_HOOK.with(|h| h.borrow_mut().take())
+}
+
+fn report_unrecognized<T>(value: &serde_json::Value) {
+    tracing::error!("{value:?} not recognized as {}", type_name::<T>());
+
+    #[cfg(feature = "unrecognized-hook")]
+    UNRECOGNIZED_HOOK.with(|h| {
+        if let Some(hook) = h.borrow().as_ref() {
+            hook(value, type_name::<T>())
+        }
+    });
+}
+
 pub(crate) fn maybe_recognized_from_str<T: FromStr>(value: &str) -> MaybeRecognizedResult<T> {
     T::from_str(value).map_err(|_| {
-        tracing::error!("{value:?} not recognized as {}", type_name::<T>());
-        NotRecognized(serde_json::Value::String(value.to_string()))
+        let value = serde_json::Value::String(value.to_string());
+        report_unrecognized::<T>(&value);
+        NotRecognized(value)
     })
 }
 
 pub(crate) fn maybe_recognized_to_string<T: ToString>(value: &MaybeRecognizedResult<T>) -> String {
     match value {
         Ok(t) => t.to_string(),
+        // Otherwise strings come out quoted
+        Err(NotRecognized(serde_json::Value::String(v))) => v.clone(),
         Err(NotRecognized(v)) => v.to_string(),
     }
 }
@@ -238,7 +311,7 @@ where
         match Visitor::<T, U>::deserialize(deserializer) {
             Ok(Visitor::Recognized(t)) => Ok(Ok(t.into_inner())),
             Ok(Visitor::Other(s)) => {
-                tracing::error!("{s:?} not recognized as {}", type_name::<T>());
+                report_unrecognized::<T>(&s);
                 Ok(Err(NotRecognized(s)))
             }
             Err(e) => Err(e),
@@ -470,11 +543,67 @@ impl SerializeAs<PitchType> for PitchTypeAcronymHelper {
 
 #[cfg(test)]
 mod test_utils {
-    use serde::{de::DeserializeOwned, Serialize};
+    use serde::{de::DeserializeOwned, Deserialize, Serialize};
     use std::{fs::File, io::Read, path::Path};
     use tracing::{subscriber::DefaultGuard, Level, Subscriber};
     use tracing_subscriber::{layer::SubscriberExt, Layer};
 
+    use crate::{
+        enums::FeedEventSource,
+        feed_event::FeedEvent,
+        player_feed::{parse_player_feed_event, ParsedPlayerFeedEventText},
+        team_feed::{parse_team_feed_event, ParsedTeamFeedEventText},
+    };
+
+    /// A regular season feed event, for tests that only care about what's parsed from its text
+    pub(crate) fn feed_event(season: u8, day: u16, event_type: &str, text: &str) -> FeedEvent {
+        serde_json::from_value(serde_json::json!({
+            "emoji": "📦",
+            "season": season,
+            "day": day,
+            "status": "Regular Season",
+            "type": event_type,
+            "text": text,
+            "ts": "2025-06-09T13:53:55.598427+00:00",
+            "links": [],
+        }))
+        .expect("test feed event should deserialize")
+    }
+
+    /// A game event's raw JSON, with `fields` overriding the defaults. Deserializes to whichever
+    /// of `Event` and `RawEvent` the test needs.
+    pub(crate) fn game_event<T: DeserializeOwned>(fields: serde_json::Value) -> T {
+        let mut event = serde_json::json!({
+            "away_score": 0,
+            "balls": 0,
+            "batter": "Yumi Pratt",
+            "event": "Pitch",
+            "home_score": 0,
+            "index": 6,
+            "inning": 1,
+            "inning_side": 0,
+            "message": "Yumi Pratt walks intentionally.",
+            "on_1b": false,
+            "on_2b": false,
+            "on_3b": false,
+            "on_deck": "Kelly Vieira",
+            "outs": 0,
+            "pitch_info": "",
+            "pitcher": "Ye-Jin Jallow",
+            "strikes": 0,
+            "zone": "",
+        });
+        let serde_json::Value::Object(fields) = fields else {
+            panic!("test game event overrides should be an object");
+        };
+        event
+            .as_object_mut()
+            .expect("test game event defaults are an object")
+            .extend(fields);
+
+        serde_json::from_value(event).expect("test game event should deserialize")
+    }
+
     pub(crate) fn no_tracing_errs() -> DefaultGuard {
         let subscriber = tracing_subscriber::fmt().finish().with(NoErrorsLayer);
         tracing::subscriber::set_default(subscriber)
@@ -509,4 +638,141 @@ mod test_utils {
         assert!(diff.is_none(), "{diff:?}");
         Ok(())
     }
+
+    /// Asserts that unparsed text matches the original, except that a run of spaces counts the same
+    /// as a single space, because mmolb sometimes doubles the spaces in names. That's the only
+    /// difference it ignores: the spaces still have to be there, and tabs, newlines, case,
+    /// punctuation and leading or trailing spaces all have to match exactly.
+    pub(crate) fn assert_unparse_eq(original: &str, produced: &str) {
+        fn collapse_spaces(s: &str) -> String {
+            let mut out = String::with_capacity(s.len());
+            for c in s.chars() {
+                if !(c == ' ' && out.ends_with(' ')) {
+                    out.push(c);
+                }
+            }
+            out
+        }
+
+        assert_eq!(
+            collapse_spaces(original),
+            collapse_spaces(produced),
+            "unparsed text doesn't match\noriginal: {original:?}\nproduced: {produced:?}"
+        );
+    }
+
+    #[test]
+    fn unparse_eq_ignores_doubled_spaces() {
+        assert_unparse_eq(
+            "🦆 Ducksburg  Mallards vs. 🐝 Beehive Buzzers - FINAL 3-7",
+            "🦆 Ducksburg Mallards vs. 🐝 Beehive Buzzers - FINAL 3-7",
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "unparsed text doesn't match")]
+    fn unparse_eq_rejects_changed_word() {
+        assert_unparse_eq(
+            "🦆 Ducksburg Mallards vs. 🐝 Beehive Buzzers - FINAL 3-7",
+            "🦆 Ducksburg Mallards at 🐝 Beehive Buzzers - FINAL 3-7",
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "unparsed text doesn't match")]
+    fn unparse_eq_rejects_missing_space() {
+        assert_unparse_eq(
+            "Yumi Pratt gained +5 Contact.",
+            "Yumi Prattgained +5 Contact.",
+        );
+    }
+
+    /// Parses every event in every feed fixture in `dir` (each either `{"feed": [...]}` as
+    /// returned by the API, or one event per line). Events that fail to parse are excused if their
+    /// text contains a line from `allowlist`, which is that source's
+    /// `test_data/{source}_feed_parse_allowlist.txt`. Fails on any failure that isn't excused, and
+    /// on any allowlist line that doesn't excuse anything, so the list can't hide parsers that
+    /// work now.
+    pub(crate) fn assert_feeds_parse_or_allowlisted(
+        dir: &Path,
+        source: FeedEventSource,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        #[derive(Deserialize)]
+        struct Feed {
+            feed: Vec<FeedEvent>,
+        }
+
+        let allowlist_path = match source {
+            FeedEventSource::Team => "test_data/team_feed_parse_allowlist.txt",
+            FeedEventSource::Player => "test_data/player_feed_parse_allowlist.txt",
+        };
+        let allowlist = std::fs::read_to_string(allowlist_path)?;
+        let allowlist = allowlist
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .collect::<Vec<_>>();
+        let mut used = vec![false; allowlist.len()];
+        let mut failures = Vec::new();
+
+        for entry in dir.read_dir()? {
+            let path = entry?.path();
+            let mut buf = String::new();
+            File::open(&path)?.read_to_string(&mut buf)?;
+
+            let events: Vec<FeedEvent> = match serde_json::from_str::<Feed>(&buf) {
+                Ok(feed) => feed.feed,
+                Err(_) => buf
+                    .lines()
+                    .filter(|line| !line.trim().is_empty())
+                    .map(serde_json::from_str)
+                    .collect::<Result<_, _>>()?,
+            };
+
+            for event in &events {
+                let failed = match source {
+                    FeedEventSource::Team => matches!(
+                        parse_team_feed_event(event),
+                        ParsedTeamFeedEventText::ParseError { .. }
+                    ),
+                    FeedEventSource::Player => matches!(
+                        parse_player_feed_event(event),
+                        ParsedPlayerFeedEventText::ParseError { .. }
+                    ),
+                };
+                if !failed {
+                    continue;
+                }
+
+                let mut excused = false;
+                for (allowed, used) in allowlist.iter().zip(&mut used) {
+                    if event.text.contains(allowed) {
+                        *used = true;
+                        excused = true;
+                    }
+                }
+                if !excused {
+                    failures.push(format!("{}: {}", path.display(), event.text));
+                }
+            }
+        }
+
+        assert!(
+            failures.is_empty(),
+            "{} events failed to parse:\n{}",
+            failures.len(),
+            failures.join("\n")
+        );
+        let stale = allowlist
+            .iter()
+            .zip(&used)
+            .filter(|(_, used)| !**used)
+            .map(|(allowed, _)| *allowed)
+            .collect::<Vec<_>>();
+        assert!(
+            stale.is_empty(),
+            "{allowlist_path} has entries that no failing event matches anymore:\n{}",
+            stale.join("\n")
+        );
+        Ok(())
+    }
 }